@@ -0,0 +1,238 @@
+//! # The Chain Module
+//!
+//! follows a cusip through its original auction and its reopenings.
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "blocking")]
+use crate::treasury::BlockingTreasuryAccess;
+use crate::{
+    client::TreasuryClient,
+    treasury::{
+        AsyncTreasuryAccess, AuctionResult, AuctionResultError, Treasuries, Treasury,
+        TreasuryAccess, BASIS_POINTS,
+    },
+    Get,
+};
+
+/// The auctions of a cusip, ordered from the original issue to the last reopening.
+pub type ReopeningChain = Vec<ChainLink>;
+
+/// An auction of a reopening chain with the amount issued so far and the
+/// changes to the auction before.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChainLink {
+    treasury: Treasury,
+    cumulative_accepted: Option<Decimal>,
+    bid_to_cover_change: Option<Decimal>,
+    rate_change: Option<Decimal>,
+}
+
+impl ChainLink {
+    /// Return the treasury of the auction.
+    pub fn get_treasury(&self) -> &Treasury {
+        &self.treasury
+    }
+
+    /// Set the when-issued yield of the auction, to compute its tail.
+    pub fn set_when_issued_yield(&mut self, when_issued_yield: Decimal) {
+        self.treasury.set_when_issued_yield(when_issued_yield);
+    }
+
+    /// Return the size of the auction, the total amount accepted.
+    pub fn get_size(&self) -> Option<Decimal> {
+        self.treasury.get_total_accepted()
+    }
+
+    /// Return the amount issued by this and all prior auctions of the cusip,
+    /// [`None`] if the size of one of them is missing.
+    pub fn get_cumulative_accepted(&self) -> Option<Decimal> {
+        self.cumulative_accepted
+    }
+
+    /// Return the change of the bid to cover ratio to the prior auction.
+    pub fn get_bid_to_cover_change(&self) -> Option<Decimal> {
+        self.bid_to_cover_change
+    }
+
+    /// Return the change of the auction rate to the prior auction in basis
+    /// points, see [`Treasury::get_auction_rate`].
+    pub fn get_rate_change(&self) -> Option<Decimal> {
+        self.rate_change
+    }
+}
+
+/// Descriptor of the Chain module.
+pub struct Chain<'c> {
+    client: &'c TreasuryClient,
+    cusip: String,
+}
+
+impl TreasuryAccess<ReopeningChain> for Chain<'_> {
+    fn url(&self) -> String {
+        self.get_command().url()
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl BlockingTreasuryAccess<ReopeningChain> for Chain<'_> {
+    /// Get every auction of the cusip in the order they were held.
+    fn get(&self) -> AuctionResult<ReopeningChain> {
+        self.link(self.get_command().get()?)
+    }
+}
+
+impl AsyncTreasuryAccess<ReopeningChain> for Chain<'_> {
+    /// Get every auction of the cusip without blocking the current thread.
+    async fn get_async(&self) -> AuctionResult<ReopeningChain> {
+        self.link(self.get_command().get_async().await?)
+    }
+}
+
+impl Chain<'static> {
+    /// Create a new Chain module from a cusip number, using the shared client.
+    pub fn new(cusip: impl Into<String>) -> Self {
+        Self::with_client(TreasuryClient::shared(), cusip)
+    }
+}
+
+impl<'c> Chain<'c> {
+    /// Create a new Chain module from a cusip number, using the given client.
+    pub fn with_client(client: &'c TreasuryClient, cusip: impl Into<String>) -> Self {
+        Self {
+            client,
+            cusip: cusip.into(),
+        }
+    }
+
+    /// Return the cusip number of the chain.
+    pub fn cusip(&self) -> &str {
+        self.cusip.as_str()
+    }
+
+    /// Create the get module for the auctions of the cusip.
+    fn get_command(&self) -> Get<'c> {
+        Get::with_client(self.client, &self.cusip)
+    }
+
+    /// Link the auctions of the cusip, failing if there is none.
+    fn link(&self, treasuries: Treasuries) -> AuctionResult<ReopeningChain> {
+        let cusip = self.cusip.trim().to_ascii_uppercase();
+        let treasuries = treasuries
+            .into_iter()
+            .filter(|treasury| treasury.cusip() == cusip)
+            .collect::<Treasuries>();
+
+        if treasuries.is_empty() {
+            return Err(AuctionResultError::EmptyResult);
+        }
+
+        Ok(chain(treasuries))
+    }
+}
+
+/// Order the auctions of a cusip by their auction date and link each one to
+/// the auction before.
+pub fn chain(mut treasuries: Treasuries) -> ReopeningChain {
+    treasuries.sort_by_key(|treasury| treasury.get_auction_date());
+
+    let mut links: ReopeningChain = Vec::with_capacity(treasuries.len());
+    let mut cumulative_accepted = Some(Decimal::ZERO);
+
+    for treasury in treasuries {
+        cumulative_accepted = cumulative_accepted
+            .zip(treasury.get_total_accepted())
+            .and_then(|(cumulative, size)| cumulative.checked_add(size));
+
+        let prior = links.last().map(|link| &link.treasury);
+        let bid_to_cover_change = prior.and_then(|prior| {
+            change(
+                treasury.get_bid_to_cover_ratio(),
+                prior.get_bid_to_cover_ratio(),
+            )
+        });
+        let rate_change = prior
+            .and_then(|prior| change(treasury.get_auction_rate(), prior.get_auction_rate()))
+            .and_then(|change| change.checked_mul(BASIS_POINTS));
+
+        links.push(ChainLink {
+            treasury,
+            cumulative_accepted,
+            bid_to_cover_change,
+            rate_change,
+        });
+    }
+
+    links
+}
+
+/// Return the change of the value to the prior value, [`None`] if one is missing.
+fn change(value: Option<Decimal>, prior: Option<Decimal>) -> Option<Decimal> {
+    value
+        .zip(prior)
+        .and_then(|(value, prior)| value.checked_sub(prior))
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::Matcher;
+    use rust_decimal_macros::dec;
+
+    use crate::{get::TREASURIES_URL, tests::fixture::api_many_items};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn it_should_link_the_reopenings_of_a_cusip() {
+        let mut server = mockito::Server::new_async().await;
+        let client = TreasuryClient::builder()
+            .base_url(server.url())
+            .build()
+            .unwrap();
+        let chain = Chain::with_client(&client, "912810TC2");
+
+        server
+            .mock("GET", TREASURIES_URL)
+            .match_query(Matcher::UrlEncoded("cusip".into(), "912810TC2".into()))
+            .with_body(api_many_items())
+            .create_async()
+            .await;
+
+        let links = chain.get_async().await.unwrap();
+
+        assert_eq!(4, links.len());
+        assert!(!links[0].get_treasury().is_reopening());
+        assert!(links[1..]
+            .iter()
+            .all(|link| link.get_treasury().is_reopening()));
+        assert_eq!(None, links[0].get_bid_to_cover_change());
+        assert_eq!(Some(dec!(24895749600)), links[0].get_cumulative_accepted());
+        assert_eq!(Some(dec!(70627285900)), links[3].get_cumulative_accepted());
+        assert_eq!(Some(dec!(0.58)), links[1].get_bid_to_cover_change());
+        assert_eq!(Some(dec!(-19.30)), links[1].get_rate_change());
+        assert_eq!(Some(dec!(26.80)), links[3].get_rate_change());
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_without_an_auction() {
+        let mut server = mockito::Server::new_async().await;
+        let client = TreasuryClient::builder()
+            .base_url(server.url())
+            .build()
+            .unwrap();
+        let chain = Chain::with_client(&client, "91282CJD4");
+
+        server
+            .mock("GET", TREASURIES_URL)
+            .match_query(Matcher::Any)
+            .with_body(api_many_items())
+            .create_async()
+            .await;
+
+        assert!(matches!(
+            chain.get_async().await,
+            Err(AuctionResultError::EmptyResult)
+        ));
+    }
+}
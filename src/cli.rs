@@ -4,23 +4,43 @@
 
 #[cfg(feature = "quality")]
 use auctionresult::quality;
+#[cfg(feature = "quality")]
+use auctionresult::quality::{profile::DEFAULT_PROFILE, Lookback, Profiles};
 
+use auctionresult::chain::ChainLink;
 use auctionresult::security_vprint;
 use auctionresult::tenor::Tenor;
+use auctionresult::tenor::TenorRange;
+use auctionresult::treasury::print::{chain_json_print, chain_print, chain_vprint};
+use auctionresult::treasury::print::security_json_print;
 use auctionresult::treasury::print::security_print;
 use auctionresult::treasury::AuctionResultError;
-use auctionresult::treasury::TreasuryAccess;
+use auctionresult::treasury::Cache;
+use auctionresult::treasury::CacheMode;
+use auctionresult::treasury::RetryPolicy;
+use auctionresult::treasury::Treasuries;
+use auctionresult::treasury::BlockingTreasuryAccess;
+use auctionresult::Chain;
+use auctionresult::Cusip;
+use auctionresult::Decimal;
 use auctionresult::Get;
+use auctionresult::GetMany;
+use auctionresult::TreasuryClient;
+use auctionresult::WhenIssued;
 use auctionresult::Latest;
 use auctionresult::SecurityType;
 
+use std::error::Error;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::path::PathBuf;
 use std::process::exit;
 use std::str::FromStr;
+use std::time::Duration;
 
 use clap::Parser;
 use clap::Subcommand;
 use clap::ValueHint;
-use reqwest::StatusCode;
 
 // The Parser.
 #[derive(Debug, Parser)]
@@ -32,9 +52,36 @@ use reqwest::StatusCode;
 )]
 #[command(version)]
 pub struct AuctionResultParser {
-    #[arg(short = 'E', long, value_name = "vertical")]
+    #[arg(short = 'E', long, global = true, value_name = "vertical")]
     /// Display result not as a table.
     pub vertical: bool,
+    #[arg(long, global = true, conflicts_with = "vertical")]
+    /// Display the result as json, which can be loaded again.
+    pub json: bool,
+    #[arg(long, global = true, conflicts_with = "refresh")]
+    /// Serve the results only from the cache.
+    pub offline: bool,
+    #[arg(long, global = true)]
+    /// Bypass the cache and update it with fresh results.
+    pub refresh: bool,
+    #[arg(long, global = true, value_name = "url", env = "ARS_BASE_URL")]
+    /// Use another base url than the TreasuryDirect api, i. e. an internal mirror.
+    pub base_url: Option<String>,
+    #[arg(long, global = true, value_name = "seconds")]
+    /// The timeout of a request in seconds.
+    pub timeout: Option<u64>,
+    #[arg(long, global = true, value_name = "url", env = "ARS_PROXY")]
+    /// Send all requests through the given proxy.
+    pub proxy: Option<String>,
+    #[arg(long, global = true, value_name = "attempts")]
+    /// The number of attempts of a request that fails with a transient error.
+    pub retries: Option<u32>,
+    #[arg(long, global = true, value_name = "file", value_hint = ValueHint::FilePath)]
+    /// Read the when-issued yields at the auctions from a csv of cusip numbers and yields.
+    pub wi_file: Option<PathBuf>,
+    #[arg(long, global = true, value_name = "file", value_hint = ValueHint::FilePath, env = "ARS_PROFILES")]
+    /// Read the scoring profiles of the quality from a toml file instead of the config directory.
+    pub profiles: Option<PathBuf>,
     #[clap(subcommand)]
     pub command: AuctionResultCommands,
 }
@@ -44,25 +91,68 @@ pub struct AuctionResultParser {
 #[clap(author, version, about)]
 pub enum AuctionResultCommands {
     #[command(arg_required_else_help = true)]
-    /// Retrieve informations about a specific security.
+    /// Retrieve informations about specific securities.
     Get {
         #[arg(value_name = "cusip", value_hint = ValueHint::CommandString)]
-        /// Retrieve the details of a treasury with the given cusip number.
-        cusip: String,
+        /// Retrieve the details of the treasuries with the given cusip numbers. Use - to read them from stdin.
+        cusips: Vec<String>,
+        #[arg(value_name = "file", long, value_hint = ValueHint::FilePath)]
+        /// Read the cusip numbers from a file, one per line.
+        file: Option<PathBuf>,
+        #[arg(value_name = "concurrency", long)]
+        /// The number of cusip numbers that are looked up at the same time.
+        concurrency: Option<usize>,
+        #[arg(value_name = "yield", long)]
+        /// The when-issued yield at the auction in percent, to compute the tail of a single cusip.
+        wi: Option<Decimal>,
     },
 
     /// Retrieves the latest aution results.
     #[command(arg_required_else_help = false)]
     Latest {
         #[arg(value_name = "type", long)]
-        /// The security type.
+        /// The security type: bill, note, bond, tips, frn or cmb.
         sectype: Option<String>,
         #[arg(value_name = "days", long)]
         /// The number of days we want to look back.
         days: Option<usize>,
-        /// Filter for a specfic tenor, i. e. 10y (for all Ten Year notes)
+        /// Filter for a specfic tenor, i. e. 10y (for all Ten Year notes), or a range, i. e. 2y..10y.
         #[arg(value_name = "tenor", long)]
         tenor: Option<String>,
+        /// Match the tenor by the benchmark bucket of the remaining term, i. e. a
+        /// 9-Year 10-Month reopening under 10y.
+        #[arg(long)]
+        bucket: bool,
+        /// The maximum number of auctions, following the pages of the api.
+        #[arg(value_name = "limit", long)]
+        limit: Option<usize>,
+        #[cfg(feature = "quality")]
+        #[arg(long)]
+        /// Score every auction against the auctions of its tenor before it.
+        with_quality: bool,
+        #[cfg(feature = "quality")]
+        #[arg(long, requires = "with_quality")]
+        /// Sort the auctions by their score, the best first.
+        sort_by_score: bool,
+        #[cfg(feature = "quality")]
+        #[arg(value_name = "lookback", long, requires = "with_quality", value_parser = parse_lookback)]
+        /// The number of auctions or the window, i. e. 1y, to look back for the quality.
+        lookback: Option<Lookback>,
+        #[cfg(feature = "quality")]
+        #[arg(value_name = "name", long, requires = "with_quality")]
+        /// The scoring profile of the quality, the default profile if not given.
+        profile: Option<String>,
+    },
+
+    /// Retrieves every auction of a cusip, the original issue and its reopenings.
+    #[command(arg_required_else_help = true)]
+    Chain {
+        #[arg(value_name = "cusip", value_hint = ValueHint::CommandString)]
+        /// The cusip number of the treasury.
+        cusip: String,
+        #[arg(value_name = "yield", long)]
+        /// The when-issued yield at the latest auction of the cusip in percent, to compute its tail.
+        wi: Option<Decimal>,
     },
 
     #[cfg(feature = "quality")]
@@ -72,57 +162,254 @@ pub enum AuctionResultCommands {
         #[arg(value_name = "cusip", value_hint = ValueHint::CommandString)]
         /// Retrieve the details of a treasury with the given cusip number.
         cusip: String,
-        /// Determine the number of auctions to look back.
-        #[arg(value_name = "lookback", long)]
-        lookback: Option<usize>,
+        /// Determine the number of auctions or the window, i. e. 1y, to look back.
+        #[arg(value_name = "lookback", long, value_parser = parse_lookback)]
+        lookback: Option<Lookback>,
+        #[arg(value_name = "yield", long)]
+        /// The when-issued yield at the auction in percent, to include the tail in the quality. FRNs have no tail.
+        wi: Option<Decimal>,
+        #[arg(value_name = "name", long, default_value = DEFAULT_PROFILE)]
+        /// The scoring profile: default, foreign-demand, dealer-stress or one of the profiles file.
+        profile: String,
+        #[arg(long)]
+        /// Explain the quality by the contribution of each component.
+        explain: bool,
     },
 }
 
-/// Handle the error by printing the error message and returning the exit code.
+/// Handle the error by printing the error message with its causes and
+/// returning the exit code.
 fn handle_error(e: AuctionResultError) -> i32 {
-    match e {
-        AuctionResultError::Request(req) => {
-            println!(
-                "Invalid Request, status code: {}",
-                req.status().unwrap_or(StatusCode::IM_A_TEAPOT)
-            );
-            1
+    eprintln!("{}.", e);
+
+    let mut source = e.source();
+    while let Some(cause) = source {
+        eprintln!("  Caused by: {}", cause);
+        source = cause.source();
+    }
+
+    e.exit_code()
+}
+
+/// Return the client configured by the command line.
+fn client(args: &AuctionResultParser) -> TreasuryClient {
+    let mut builder = TreasuryClient::builder();
+
+    if let Some(base_url) = &args.base_url {
+        builder = builder.base_url(base_url);
+    }
+
+    if let Some(timeout) = args.timeout {
+        builder = builder.timeout(Duration::from_secs(timeout));
+    }
+
+    if let Some(proxy) = &args.proxy {
+        builder = builder.proxy(proxy);
+    }
+
+    if let Some(retries) = args.retries {
+        builder = builder.retry(RetryPolicy::new(retries));
+    }
+
+    let mode = if args.offline {
+        CacheMode::Offline
+    } else if args.refresh {
+        CacheMode::Refresh
+    } else {
+        CacheMode::Normal
+    };
+
+    if let Some(dir) = Cache::default_dir() {
+        builder = builder.cache(Cache::new(dir).with_mode(mode));
+    }
+
+    match builder.build() {
+        Ok(client) => client,
+        Err(e) => exit(handle_error(e)),
+    }
+}
+
+/// Read the cusip numbers, one per line. Only the first column of a line is
+/// used, empty lines and comments starting with # are skipped.
+fn read_cusips(reader: impl BufRead) -> io::Result<Vec<String>> {
+    let mut cusips = Vec::new();
+
+    for line in reader.lines() {
+        let line = line?;
+        let line = line.trim();
+
+        if line.is_empty() || line.starts_with('#') {
+            continue;
         }
-        AuctionResultError::RequestDyn(_) => {
-            println!("Invalid dynamic request");
-            2
+
+        if let Some(cusip) = line.split([',', ';', ' ', '\t']).next() {
+            cusips.push(cusip.to_owned());
         }
-        AuctionResultError::ParseCusip => {
-            println!("Could not parse cusip number.");
-            3
+    }
+
+    Ok(cusips)
+}
+
+/// Collect the cusip numbers from the arguments, stdin and the file.
+fn collect_cusips(cusips: &[String], file: &Option<PathBuf>) -> io::Result<Vec<String>> {
+    let mut collected = Vec::new();
+
+    for cusip in cusips {
+        if cusip == "-" {
+            collected.extend(read_cusips(io::stdin().lock())?);
+        } else {
+            collected.push(cusip.to_owned());
         }
-        AuctionResultError::ParseTenor => {
-            println!("Could not parse tenor.");
-            4
+    }
+
+    if let Some(file) = file {
+        collected.extend(read_cusips(BufReader::new(File::open(file)?))?);
+    }
+
+    Ok(collected)
+}
+
+/// Read the when-issued yields of the csv file and add the yield of the cusip.
+fn when_issued(args: &AuctionResultParser, cusip: Option<(&str, Decimal)>) -> WhenIssued {
+    let mut when_issued = match &args.wi_file {
+        Some(file) => match File::open(file) {
+            Ok(file) => match WhenIssued::parse(BufReader::new(file)) {
+                Ok(when_issued) => when_issued,
+                Err(e) => exit(handle_error(e)),
+            },
+            Err(e) => {
+                eprintln!("Could not read the when-issued yields: {}", e);
+                exit(1);
+            }
+        },
+        None => WhenIssued::new(),
+    };
+
+    if let Some((cusip, when_issued_yield)) = cusip {
+        match Cusip::parse(cusip) {
+            Ok(cusip) => when_issued.insert(cusip, when_issued_yield),
+            Err(e) => exit(handle_error(e)),
         }
-        AuctionResultError::NoTreasury => todo!(),
-        AuctionResultError::OutOfBounds => todo!(),
+    }
+
+    when_issued
+}
+
+#[cfg(feature = "quality")]
+/// Load the scoring profiles of the file given on the command line or of the config directory.
+fn profiles(args: &AuctionResultParser) -> Result<Profiles, AuctionResultError> {
+    match &args.profiles {
+        Some(file) => match std::fs::read_to_string(file) {
+            Ok(toml) => Profiles::parse(&toml),
+            Err(e) => {
+                eprintln!("Could not read the scoring profiles: {}", e);
+                exit(1);
+            }
+        },
+        None => Profiles::default_path().map_or(Ok(Profiles::default()), Profiles::load),
+    }
+}
+
+#[cfg(feature = "quality")]
+/// Parse the lookback of the command line, a number of auctions or a window.
+fn parse_lookback(s: &str) -> Result<Lookback, String> {
+    Lookback::parse(s).map_err(|e| e.to_string())
+}
+
+/// Return the printer of treasuries selected by the command line.
+fn printer(args: &AuctionResultParser) -> fn(&Treasuries) {
+    if args.json {
+        security_json_print
+    } else if args.vertical {
+        security_vprint
+    } else {
+        security_print
     }
 }
 
 /// Handle the command get.
 pub fn handle_get(args: &AuctionResultParser) {
-    let AuctionResultCommands::Get { cusip } = &args.command else {
+    let AuctionResultCommands::Get {
+        cusips,
+        file,
+        concurrency,
+        wi,
+    } = &args.command
+    else {
         exit(handle_error(AuctionResultError::ParseCusip));
     };
 
-    let get_command = Get::new(cusip);
+    let cusips = match collect_cusips(cusips, file) {
+        Ok(cusips) => cusips,
+        Err(e) => {
+            eprintln!("Could not read the cusip numbers: {}", e);
+            exit(1);
+        }
+    };
 
-    let treasuries = match get_command.get() {
-        Ok(vec) => vec,
-        Err(e) => exit(handle_error(e)),
+    if cusips.is_empty() {
+        eprintln!("No cusip numbers given.");
+        exit(1);
+    }
+
+    // A single cusip number with a typo is rejected before asking the api.
+    if let [cusip] = cusips.as_slice() {
+        if let Err(e) = Cusip::parse(cusip) {
+            exit(handle_error(e));
+        }
+    }
+
+    // The when-issued yield of the command line belongs to a single cusip number.
+    let when_issued = match (cusips.as_slice(), wi) {
+        ([cusip], Some(wi)) => when_issued(args, Some((cusip, *wi))),
+        (_, Some(_)) => {
+            eprintln!("The when-issued yield needs a single cusip number, use --wi-file.");
+            exit(1);
+        }
+        (_, None) => when_issued(args, None),
     };
 
-    (if args.vertical {
-        security_vprint
-    } else {
-        security_print
-    })(&treasuries)
+    let client = client(args);
+
+    // A single cusip number fails as before, a batch reports every failure.
+    if let [cusip] = cusips.as_slice() {
+        let mut treasuries = match Get::with_client(&client, cusip).get() {
+            Ok(vec) => vec,
+            Err(e) => exit(handle_error(e)),
+        };
+
+        when_issued.apply(&mut treasuries);
+        return printer(args)(&treasuries);
+    }
+
+    let mut get_many = GetMany::with_client(&client, cusips);
+
+    if let Some(concurrency) = concurrency {
+        get_many.set_concurrency(*concurrency);
+    }
+
+    let mut treasuries = Vec::new();
+    let mut exit_code = 0;
+
+    for (cusip, result) in get_many.get() {
+        match result {
+            Ok(vec) => treasuries.extend(vec),
+            Err(e) => {
+                eprint!("{}: ", cusip);
+                let code = handle_error(e);
+                if exit_code == 0 {
+                    exit_code = code;
+                }
+            }
+        }
+    }
+
+    when_issued.apply(&mut treasuries);
+    printer(args)(&treasuries);
+
+    if exit_code != 0 {
+        exit(exit_code);
+    }
 }
 
 /// Handle the command lastest.
@@ -131,6 +418,9 @@ pub fn handle_latest(args: &AuctionResultParser) {
         sectype,
         days,
         tenor,
+        bucket,
+        limit,
+        ..
     } = &args.command
     else {
         panic!("Cannot extract the security type and/or the number of days to look back.")
@@ -151,45 +441,222 @@ pub fn handle_latest(args: &AuctionResultParser) {
     let default_tenor = String::from("");
     let tenor_str = tenor.as_ref().unwrap_or(&default_tenor);
 
+    // A range of tenors, i. e. 2y..10y, filters instead of a single tenor.
+    let (tenor_str, range) = if TenorRange::is_range(tenor_str) {
+        match TenorRange::parse(tenor_str) {
+            Ok(range) => ("", Some(range)),
+            Err(e) => exit(handle_error(e)),
+        }
+    } else {
+        (tenor_str.as_str(), None)
+    };
+
     let Ok(tenor) = Tenor::parse(tenor_str) else {
         println!("Error parsing tenor option!");
         exit(4);
     };
 
-    let latest_command = Latest::new(security_type, look_back_days, tenor);
+    let client = client(args);
+    let mut latest_command = Latest::with_client(&client, security_type, look_back_days, tenor);
+
+    if let Some(range) = range {
+        latest_command.set_range(range);
+    }
+
+    latest_command.set_bucketing(*bucket);
+
+    if let Some(limit) = limit {
+        latest_command.set_limit(*limit);
+    }
 
     let response = latest_command.get();
 
-    let securities = match response {
+    let mut securities = match response {
         Ok(vec) => vec,
         Err(e) => exit(handle_error(e)),
     };
 
-    (if args.vertical {
-        security_vprint
+    when_issued(args, None).apply(&mut securities);
+
+    #[cfg(feature = "quality")]
+    if let AuctionResultCommands::Latest {
+        with_quality: true,
+        ..
+    } = &args.command
+    {
+        return handle_latest_quality(args, &client, &securities);
+    }
+
+    printer(args)(&securities)
+}
+
+#[cfg(feature = "quality")]
+/// Print the qualities of the latest auctions, scored in one pass.
+fn handle_latest_quality(
+    args: &AuctionResultParser,
+    client: &TreasuryClient,
+    securities: &Treasuries,
+) {
+    use auctionresult::treasury::print::{
+        auction_qualities_json_print, auction_qualities_print, auction_quality_print,
+    };
+
+    let AuctionResultCommands::Latest {
+        sort_by_score,
+        lookback,
+        profile,
+        ..
+    } = &args.command
+    else {
+        panic!("Cannot extract the options of the quality.")
+    };
+
+    let mut batch =
+        quality::QualityBatch::with_client(client, lookback.to_owned().unwrap_or_default());
+    batch.set_when_issued(when_issued(args, None));
+
+    let profile = profile.as_deref().unwrap_or(DEFAULT_PROFILE);
+    match profiles(args).and_then(|profiles| profiles.get(profile)) {
+        Ok(profile) => batch.set_profile(profile),
+        Err(e) => exit(handle_error(e)),
+    }
+
+    let results = match batch.calculate(securities) {
+        Ok(results) => results,
+        Err(e) => exit(handle_error(e)),
+    };
+
+    let mut qualities = Vec::new();
+    let mut exit_code = 0;
+
+    for (security, result) in securities.iter().zip(results) {
+        match result {
+            Ok(quality) => qualities.push(quality),
+            Err(e) => {
+                eprint!("{}: ", security.cusip());
+                let code = handle_error(e);
+                if exit_code == 0 {
+                    exit_code = code;
+                }
+            }
+        }
+    }
+
+    if *sort_by_score {
+        quality::sort_by_score(&mut qualities);
+    }
+
+    if args.json {
+        auction_qualities_json_print(&qualities);
+    } else if args.vertical {
+        qualities.iter().for_each(auction_quality_print);
     } else {
-        security_print
-    })(&securities)
+        auction_qualities_print(&qualities);
+    }
+
+    if exit_code != 0 {
+        exit(exit_code);
+    }
+}
+
+/// Handle the command chain.
+pub fn handle_chain(args: &AuctionResultParser) {
+    let AuctionResultCommands::Chain { cusip, wi } = &args.command else {
+        exit(handle_error(AuctionResultError::ParseCusip));
+    };
+
+    if let Err(e) = Cusip::parse(cusip) {
+        exit(handle_error(e));
+    }
+
+    let client = client(args);
+    let mut links = match Chain::with_client(&client, cusip).get() {
+        Ok(links) => links,
+        Err(e) => exit(handle_error(e)),
+    };
+
+    // The reopenings share the cusip, its when-issued yield is the one of the latest auction.
+    let when_issued = when_issued(args, wi.map(|wi| (cusip.as_str(), wi)));
+    let when_issued_yield = Cusip::parse(cusip).ok().and_then(|c| when_issued.get(&c));
+
+    if let Some((link, when_issued_yield)) = links.last_mut().zip(when_issued_yield) {
+        link.set_when_issued_yield(when_issued_yield);
+    }
+
+    chain_printer(args)(&links)
+}
+
+/// Return the printer of a reopening chain selected by the command line.
+fn chain_printer(args: &AuctionResultParser) -> fn(&[ChainLink]) {
+    if args.json {
+        chain_json_print
+    } else if args.vertical {
+        chain_vprint
+    } else {
+        chain_print
+    }
 }
 
 #[cfg(feature = "quality")]
 /// Handle the quality command.
 pub fn handle_quality(args: &AuctionResultParser) {
-    use auctionresult::treasury::print::auction_quality_print;
+    use auctionresult::treasury::print::{
+        auction_quality_explain_print, auction_quality_json_print, auction_quality_print,
+    };
 
     #[cfg(feature = "quality")]
-    let AuctionResultCommands::Quality { cusip, lookback } = &args.command
+    let AuctionResultCommands::Quality {
+        cusip,
+        lookback,
+        wi,
+        profile,
+        explain,
+    } = &args.command
     else {
         exit(handle_error(AuctionResultError::ParseCusip));
     };
 
-    let number_of_auctions = lookback.unwrap_or(0);
-    let quality_command = quality::QualityCommand::new(cusip, number_of_auctions);
+    if let Err(e) = Cusip::parse(cusip) {
+        exit(handle_error(e));
+    }
+
+    let when_issued = when_issued(args, wi.map(|wi| (cusip.as_str(), wi)));
+    let lookback = lookback.to_owned().unwrap_or_default();
+    let client = client(args);
+    let mut quality_command = quality::QualityCommand::with_client(&client, cusip, lookback);
+
+    if let Some(when_issued_yield) = Cusip::parse(cusip).ok().and_then(|c| when_issued.get(&c)) {
+        quality_command.set_when_issued_yield(when_issued_yield);
+    }
+
+    match profiles(args).and_then(|profiles| profiles.get(profile)) {
+        Ok(profile) => quality_command.set_profile(profile),
+        Err(e) => exit(handle_error(e)),
+    }
     let result = quality_command.calculate();
 
     let Ok(q) = result else {
         exit(handle_error(result.unwrap_err()))
     };
 
-    auction_quality_print(&q);
+    if args.json {
+        auction_quality_json_print(&q);
+    } else {
+        auction_quality_print(&q);
+
+        if *explain {
+            auction_quality_explain_print(&q);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::CommandFactory;
+
+    #[test]
+    fn it_should_define_a_consistent_command_line() {
+        AuctionResultParser::command().debug_assert();
+    }
 }
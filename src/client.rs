@@ -0,0 +1,235 @@
+//! # The client shared by all modules.
+//!
+//! The client owns the base url of the api, the settings of the http
+//! connection, the retry policy, the rate limiter and the response cache.
+//! All responses are loaded through the [`Transport`] of the client.
+use std::{
+    sync::{Arc, OnceLock},
+    time::Duration,
+};
+
+use crate::{
+    transport::{HttpTransport, Transport},
+    treasury::{AuctionResult, Cache, RateLimiter, RetryPolicy},
+};
+
+/// The base url of the TreasuryDirect api.
+pub const DEFAULT_BASE_URL: &str = "https://www.treasurydirect.gov/TA_WS";
+
+/// The default timeout of a request.
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// The default user agent sent with every request.
+const DEFAULT_USER_AGENT: &str = concat!("auctionresult/", env!("CARGO_PKG_VERSION"));
+
+/// The default number of requests per second.
+const DEFAULT_REQUESTS_PER_SECOND: u32 = 4;
+
+/// The client used by the [`Get`](crate::Get), [`Latest`](crate::Latest) and
+/// [`QualityCommand`](crate::quality::QualityCommand) modules.
+#[derive(Debug, Clone)]
+pub struct TreasuryClient {
+    base_url: String,
+    timeout: Duration,
+    user_agent: String,
+    proxy: Option<String>,
+    cache: Option<Cache>,
+    retry: RetryPolicy,
+    rate_limiter: Arc<RateLimiter>,
+    transport: Arc<dyn Transport>,
+}
+
+impl Default for TreasuryClient {
+    fn default() -> Self {
+        let rate_limiter = Arc::new(RateLimiter::per_second(DEFAULT_REQUESTS_PER_SECOND));
+        let transport = HttpTransport::new(DEFAULT_TIMEOUT, DEFAULT_USER_AGENT)
+            .with_rate_limiter(rate_limiter.clone());
+
+        Self {
+            base_url: DEFAULT_BASE_URL.to_owned(),
+            timeout: DEFAULT_TIMEOUT,
+            user_agent: DEFAULT_USER_AGENT.to_owned(),
+            proxy: None,
+            cache: None,
+            retry: RetryPolicy::default(),
+            rate_limiter,
+            transport: Arc::new(transport),
+        }
+    }
+}
+
+impl TreasuryClient {
+    /// Create a new client with the default settings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Return a builder to configure a new client.
+    pub fn builder() -> TreasuryClientBuilder {
+        TreasuryClientBuilder::default()
+    }
+
+    /// Return the client shared by all modules that are created without one.
+    pub fn shared() -> &'static TreasuryClient {
+        static SHARED: OnceLock<TreasuryClient> = OnceLock::new();
+        SHARED.get_or_init(TreasuryClient::default)
+    }
+
+    /// Return the base url of the api.
+    pub fn base_url(&self) -> &str {
+        self.base_url.as_str()
+    }
+
+    /// Return the timeout of a request.
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    /// Return the user agent sent with every request.
+    pub fn user_agent(&self) -> &str {
+        self.user_agent.as_str()
+    }
+
+    /// Return the proxy all requests are sent through.
+    pub fn proxy(&self) -> Option<&str> {
+        self.proxy.as_deref()
+    }
+
+    /// Return the response cache.
+    pub fn cache(&self) -> Option<&Cache> {
+        self.cache.as_ref()
+    }
+
+    /// Return the retry policy of failed requests.
+    pub fn retry(&self) -> &RetryPolicy {
+        &self.retry
+    }
+
+    /// Return the rate limiter shared by all requests of this client and its clones.
+    pub fn rate_limiter(&self) -> &RateLimiter {
+        &self.rate_limiter
+    }
+
+    /// Return the transport all responses are loaded through.
+    pub fn transport(&self) -> &dyn Transport {
+        self.transport.as_ref()
+    }
+}
+
+/// The builder of a [`TreasuryClient`].
+#[derive(Debug, Default)]
+pub struct TreasuryClientBuilder {
+    client: TreasuryClient,
+    transport: Option<Arc<dyn Transport>>,
+}
+
+impl TreasuryClientBuilder {
+    /// Use the given base url instead of the TreasuryDirect api, i. e. an internal mirror.
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.client.base_url = base_url.into().trim_end_matches('/').to_owned();
+        self
+    }
+
+    /// Set the timeout of a request.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.client.timeout = timeout;
+        self
+    }
+
+    /// Set the user agent sent with every request.
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.client.user_agent = user_agent.into();
+        self
+    }
+
+    /// Send all requests through the given proxy.
+    pub fn proxy(mut self, proxy: impl Into<String>) -> Self {
+        self.client.proxy = Some(proxy.into());
+        self
+    }
+
+    /// Serve the responses through the given cache.
+    pub fn cache(mut self, cache: Cache) -> Self {
+        self.client.cache = Some(cache);
+        self
+    }
+
+    /// Set the retry policy of failed requests.
+    pub fn retry(mut self, retry: RetryPolicy) -> Self {
+        self.client.retry = retry;
+        self
+    }
+
+    /// Set the rate limiter, which may be shared with other clients.
+    pub fn rate_limiter(mut self, rate_limiter: Arc<RateLimiter>) -> Self {
+        self.client.rate_limiter = rate_limiter;
+        self
+    }
+
+    /// Load all responses through the given transport instead of sending
+    /// requests to the api. The timeout, the user agent, the proxy and the
+    /// rate limiter only apply to the default transport.
+    pub fn transport(mut self, transport: Arc<dyn Transport>) -> Self {
+        self.transport = Some(transport);
+        self
+    }
+
+    /// Build the client. Fails if the proxy is not a valid url.
+    pub fn build(self) -> AuctionResult<TreasuryClient> {
+        let mut client = self.client;
+
+        client.transport = match self.transport {
+            Some(transport) => transport,
+            None => {
+                let mut transport = HttpTransport::new(client.timeout, &client.user_agent)
+                    .with_rate_limiter(client.rate_limiter.clone());
+
+                if let Some(proxy) = &client.proxy {
+                    reqwest::Proxy::all(proxy)?;
+                    transport = transport.with_proxy(proxy);
+                }
+
+                Arc::new(transport)
+            }
+        };
+
+        Ok(client)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_use_the_treasury_direct_api_by_default() {
+        let client = TreasuryClient::new();
+
+        assert_eq!(DEFAULT_BASE_URL, client.base_url());
+        assert_eq!(DEFAULT_TIMEOUT, client.timeout());
+        assert!(client.proxy().is_none());
+    }
+
+    #[test]
+    fn it_should_build_a_configured_client() {
+        let client = TreasuryClient::builder()
+            .base_url("http://localhost:8080/TA_WS/")
+            .timeout(Duration::from_secs(5))
+            .user_agent("desk")
+            .proxy("http://proxy.local:3128")
+            .build()
+            .unwrap();
+
+        assert_eq!("http://localhost:8080/TA_WS", client.base_url());
+        assert_eq!(Duration::from_secs(5), client.timeout());
+        assert_eq!("desk", client.user_agent());
+        assert_eq!(Some("http://proxy.local:3128"), client.proxy());
+    }
+
+    #[test]
+    fn it_should_reject_an_invalid_proxy() {
+        let result = TreasuryClient::builder().proxy("::invalid").build();
+
+        assert!(result.is_err());
+    }
+}
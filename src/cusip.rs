@@ -0,0 +1,165 @@
+//! # The cusip number of a treasury.
+//!
+//! A [`Cusip`] is checked when it is parsed, so a typo is rejected before
+//! the api is asked.
+use std::{fmt, str::FromStr};
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::treasury::{AuctionResult, AuctionResultError, SecurityType};
+
+/// The issuer numbers of the US Treasury and the securities they are used for.
+const ISSUERS: [(&str, SecurityType); 5] = [
+    ("912796", SecurityType::Bill),
+    ("912797", SecurityType::Bill),
+    ("912828", SecurityType::Note),
+    ("91282C", SecurityType::Note),
+    ("912810", SecurityType::Bond),
+];
+
+/// A cusip number with a valid check digit. The default is the empty cusip
+/// number of a default treasury.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Cusip(String);
+
+impl Cusip {
+    /// Parse a cusip number, ignoring surrounding whitespace and the case of
+    /// the letters. A wrong check digit is reported with the corrected number.
+    pub fn parse(s: impl AsRef<str>) -> AuctionResult<Self> {
+        let s = s.as_ref().trim().to_ascii_uppercase();
+
+        match ::cusip::parse(&s) {
+            Ok(cusip) => Ok(Self(cusip.to_string())),
+            Err(::cusip::CUSIPError::IncorrectCheckDigit { expected, .. }) => {
+                Err(AuctionResultError::CheckDigit {
+                    suggestion: format!("{}{}", &s[..8], expected as char),
+                    cusip: s,
+                })
+            }
+            Err(_) => Err(AuctionResultError::ParseCusip),
+        }
+    }
+
+    /// Complete the first eight characters of a cusip number with the check digit.
+    pub fn complete(payload: impl AsRef<str>) -> AuctionResult<Self> {
+        let payload = payload.as_ref().trim().to_ascii_uppercase();
+
+        ::cusip::build_from_payload(&payload)
+            .map(|cusip| Self(cusip.to_string()))
+            .map_err(|_| AuctionResultError::ParseCusip)
+    }
+
+    /// Return the cusip number as a string.
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+
+    /// Return the issuer number, i. e. the first six characters.
+    pub fn issuer(&self) -> &str {
+        self.0.get(..6).unwrap_or_default()
+    }
+
+    /// Return the check digit.
+    pub fn check_digit(&self) -> Option<char> {
+        self.0.chars().nth(8)
+    }
+
+    /// Classify the security by the issuer number of the US Treasury: 912796
+    /// and 912797 are bills, 912828 and 91282C notes and 912810 bonds. TIPS
+    /// and FRNs share the numbers of the notes and bonds.
+    pub fn security_type(&self) -> Option<SecurityType> {
+        ISSUERS
+            .iter()
+            .find(|(issuer, _)| *issuer == self.issuer())
+            .map(|(_, security_type)| security_type.to_owned())
+    }
+
+    /// Return if the cusip number was issued by the US Treasury.
+    pub fn is_treasury(&self) -> bool {
+        self.issuer().starts_with("9127") || self.issuer().starts_with("9128")
+    }
+}
+
+impl FromStr for Cusip {
+    type Err = AuctionResultError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Cusip::parse(s)
+    }
+}
+
+impl fmt::Display for Cusip {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl AsRef<str> for Cusip {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Serialize for Cusip {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for Cusip {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+
+        Cusip::parse(&s)
+            .map_err(|_| de::Error::invalid_value(de::Unexpected::Str(&s), &"a cusip number"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_parse_a_cusip_number() {
+        let cusip = Cusip::parse(" 912810sh2 ").unwrap();
+
+        assert_eq!("912810SH2", cusip.as_str());
+        assert_eq!("912810", cusip.issuer());
+        assert_eq!(Some('2'), cusip.check_digit());
+        assert!(matches!(
+            Cusip::parse("x1"),
+            Err(AuctionResultError::ParseCusip)
+        ));
+    }
+
+    #[test]
+    fn it_should_suggest_the_check_digit() {
+        let error = Cusip::parse("912810SH3").unwrap_err();
+
+        assert!(matches!(
+            &error,
+            AuctionResultError::CheckDigit { suggestion, .. } if suggestion == "912810SH2"
+        ));
+        assert_eq!(3, error.exit_code());
+        assert_eq!("912810SH2", Cusip::complete("912810SH").unwrap().as_str());
+    }
+
+    #[test]
+    fn it_should_classify_the_issuer() {
+        let classify = |s| Cusip::complete(s).unwrap().security_type();
+
+        assert_eq!(Some(SecurityType::Bill), classify("912797GL"));
+        assert_eq!(Some(SecurityType::Note), classify("91282CJD"));
+        assert_eq!(Some(SecurityType::Bond), classify("912810SH"));
+        assert_eq!(None, classify("037833AK"));
+        assert!(!Cusip::complete("037833AK").unwrap().is_treasury());
+    }
+
+    #[test]
+    fn it_should_deserialize_from_the_api() {
+        let cusip: Cusip = serde_json::from_str(r#""91282CJD4""#).unwrap();
+
+        assert_eq!(r#""91282CJD4""#, serde_json::to_string(&cusip).unwrap());
+        assert!(serde_json::from_str::<Cusip>(r#""91282CJD5""#).is_err());
+    }
+}
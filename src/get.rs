@@ -1,62 +1,186 @@
 //! # The Get Module
-extern crate cusip;
-use crate::treasury::{load::load, AuctionResult, AuctionResultError, Treasuries, TreasuryAccess};
-use cusip as cu;
-
-#[cfg(test)]
-static HOST: &str = "";
-
-#[cfg(not(test))]
-static HOST: &str = "https://www.treasurydirect.gov/TA_WS";
+#[cfg(feature = "blocking")]
+use crate::treasury::{load::load, BlockingTreasuryAccess};
+use crate::{
+    client::TreasuryClient,
+    cusip::Cusip,
+    treasury::{
+        load::{decode, load_async}, AsyncTreasuryAccess, AuctionResult, Treasuries,
+        TreasuryAccess,
+    },
+};
+use futures::{stream, StreamExt};
 
 // Use pub(create) for testing puposes.
 pub(crate) static TREASURIES_URL: &str = "/securities/search";
 
-pub struct Get {
+/// The default number of CUSIPs that are looked up at the same time.
+pub const DEFAULT_CONCURRENCY: usize = 4;
+
+/// The result of a single CUSIP of a batch.
+pub type BatchResult = (String, AuctionResult<Treasuries>);
+
+pub struct Get<'c> {
+    client: &'c TreasuryClient,
     cusip: String,
-    host: String,
 }
 
-impl TreasuryAccess<Treasuries> for Get {
+impl TreasuryAccess<Treasuries> for Get<'_> {
+    fn url(&self) -> String {
+        format!(
+            "{}{}?cusip={}&format=json",
+            self.client.base_url(),
+            TREASURIES_URL,
+            self.cusip.trim().to_ascii_uppercase()
+        )
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl BlockingTreasuryAccess<Treasuries> for Get<'_> {
     fn get(&self) -> AuctionResult<Treasuries> {
-        // Check the cusip number, before using it.
-        if !cu::validate(&self.cusip) {
-            return Err(AuctionResultError::ParseCusip);
-        }
+        self.validate()?;
 
         let url = self.url();
-        let response = load(url)?;
+        let body = load(self.client, url)?;
 
-        let treasuries: Treasuries = response.json()?;
+        let treasuries: Treasuries = decode(&body)?;
         Ok(treasuries)
     }
+}
 
-    fn url(&self) -> String {
-        format!(
-            "{}{}?cusip={}&format=json",
-            self.host, TREASURIES_URL, self.cusip
-        )
+impl AsyncTreasuryAccess<Treasuries> for Get<'_> {
+    async fn get_async(&self) -> AuctionResult<Treasuries> {
+        self.validate()?;
+
+        let url = self.url();
+        let body = load_async(self.client, url).await?;
+
+        let treasuries: Treasuries = decode(&body)?;
+        Ok(treasuries)
     }
 }
 
-impl Get {
-    /// Create a new Get module from a cusip number.
+impl Get<'static> {
+    /// Create a new Get module from a cusip number, using the shared client.
     pub fn new(cusip: impl Into<String>) -> Self {
+        Self::with_client(TreasuryClient::shared(), cusip)
+    }
+
+    /// Create a batch to look up many cusip numbers, using the shared client.
+    pub fn many<I, S>(cusips: I) -> GetMany<'static>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        GetMany::with_client(TreasuryClient::shared(), cusips)
+    }
+}
+
+impl<'c> Get<'c> {
+    /// Create a new Get module from a cusip number, using the given client.
+    pub fn with_client(client: &'c TreasuryClient, cusip: impl Into<String>) -> Self {
         Self {
+            client,
             cusip: cusip.into(),
-            host: String::from(HOST),
         }
     }
 
-    pub fn set_host(&mut self, host: impl Into<String>) {
-        self.host = host.into();
+    /// Check the cusip number and its check digit, before using it.
+    fn validate(&self) -> AuctionResult<()> {
+        Cusip::parse(&self.cusip).map(|_| ())
+    }
+}
+
+/// Descriptor of a batch of Get modules. Every cusip number gets its own
+/// result, so that a bad cusip number does not abort the batch.
+#[derive(Debug)]
+pub struct GetMany<'c> {
+    client: &'c TreasuryClient,
+    cusips: Vec<String>,
+    concurrency: usize,
+}
+
+impl<'c> GetMany<'c> {
+    /// Create a batch like [`Get::many`], using the given client.
+    pub fn with_client<I, S>(client: &'c TreasuryClient, cusips: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            client,
+            cusips: cusips.into_iter().map(Into::into).collect(),
+            concurrency: DEFAULT_CONCURRENCY,
+        }
+    }
+
+    /// Set the number of cusip numbers that are looked up at the same time.
+    pub fn set_concurrency(&mut self, concurrency: usize) {
+        self.concurrency = concurrency.max(1);
+    }
+
+    /// Return the cusip numbers of the batch.
+    pub fn cusips(&self) -> &[String] {
+        &self.cusips
+    }
+
+    /// Look up all cusip numbers on a bounded number of threads. The results
+    /// are returned in the order of the cusip numbers.
+    #[cfg(feature = "blocking")]
+    pub fn get(&self) -> Vec<BatchResult> {
+        use std::sync::{
+            atomic::{AtomicUsize, Ordering},
+            Mutex,
+        };
+
+        let next = AtomicUsize::new(0);
+        let results = Mutex::new(Vec::with_capacity(self.cusips.len()));
+
+        std::thread::scope(|scope| {
+            for _ in 0..self.concurrency.min(self.cusips.len()) {
+                scope.spawn(|| loop {
+                    let index = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(cusip) = self.cusips.get(index) else {
+                        break;
+                    };
+
+                    let result = Get::with_client(self.client, cusip).get();
+                    results
+                        .lock()
+                        .unwrap_or_else(|e| e.into_inner())
+                        .push((index, (cusip.clone(), result)));
+                });
+            }
+        });
+
+        let mut results = results.into_inner().unwrap_or_else(|e| e.into_inner());
+        results.sort_by_key(|(index, _)| *index);
+        results.into_iter().map(|(_, result)| result).collect()
+    }
+
+    /// Look up all cusip numbers without blocking the current thread. The
+    /// results are returned in the order of the cusip numbers.
+    pub async fn get_async(&self) -> Vec<BatchResult> {
+        stream::iter(&self.cusips)
+            .map(|cusip| async move {
+                let result = Get::with_client(self.client, cusip).get_async().await;
+                (cusip.clone(), result)
+            })
+            .buffered(self.concurrency)
+            .collect()
+            .await
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::tests::fixture::api_single_item;
+    use crate::{
+        client::DEFAULT_BASE_URL, tests::fixture::api_single_item, treasury::AuctionResultError,
+    };
+    #[cfg(feature = "blocking")]
+    use crate::treasury::RetryPolicy;
     use mockito::Matcher;
 
     const TEST_CUSIP: &str = "91282CJQ5";
@@ -66,11 +190,15 @@ mod tests {
         // if cfg!(target_os = "windows") {
         let g = Get::new(TEST_CUSIP);
         assert_eq!(
-            format!("{}?cusip={}&format=json", TREASURIES_URL, TEST_CUSIP),
+            format!(
+                "{}{}?cusip={}&format=json",
+                DEFAULT_BASE_URL, TREASURIES_URL, TEST_CUSIP
+            ),
             g.url()
         );
     }
     #[test]
+    #[cfg(feature = "blocking")]
     fn it_should_handle_an_invalid_cusip() {
         // if cfg!(target_os = "windows") {
         let g = Get::new("x1");
@@ -79,13 +207,15 @@ mod tests {
         assert!(result.is_err());
     }
     #[test]
+    #[cfg(feature = "blocking")]
     fn it_should_correctly_call_get() {
         let mut server = mockito::Server::new();
-        let host = server.url();
-
-        let mut g = Get::new(TEST_CUSIP);
+        let client = TreasuryClient::builder()
+            .base_url(server.url())
+            .build()
+            .unwrap();
 
-        g.set_host(host);
+        let g = Get::with_client(&client, TEST_CUSIP);
 
         server
             .mock("GET", TREASURIES_URL)
@@ -101,13 +231,16 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "blocking")]
     fn it_should_correctly_handle_invalid_response() {
         let mut server = mockito::Server::new();
-        let host = server.url();
-
-        let mut g = Get::new(TEST_CUSIP);
+        let client = TreasuryClient::builder()
+            .base_url(server.url())
+            .retry(RetryPolicy::none())
+            .build()
+            .unwrap();
 
-        g.set_host(host);
+        let g = Get::with_client(&client, TEST_CUSIP);
 
         server
             .mock("GET", TREASURIES_URL)
@@ -128,12 +261,112 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "blocking")]
     fn it_should_correctly_handle_a_connection_error() {
-        let mut g = Get::new(TEST_CUSIP);
         // Make sure that nothing is listening on that port.
-        g.set_host("https://localhost:12000");
+        let client = TreasuryClient::builder()
+            .base_url("https://localhost:12000")
+            .retry(RetryPolicy::none())
+            .build()
+            .unwrap();
+        let g = Get::with_client(&client, TEST_CUSIP);
         let result = g.get();
         // println!("{result:#?}");
         assert!(result.is_err());
     }
+
+    #[tokio::test]
+    async fn it_should_correctly_call_get_async() {
+        let mut server = mockito::Server::new_async().await;
+        let client = TreasuryClient::builder()
+            .base_url(server.url())
+            .build()
+            .unwrap();
+
+        let g = Get::with_client(&client, TEST_CUSIP);
+
+        server
+            .mock("GET", TREASURIES_URL)
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("cusip".into(), TEST_CUSIP.into()),
+                Matcher::UrlEncoded("format".into(), "json".into()),
+            ]))
+            .with_body(api_single_item())
+            .create_async()
+            .await;
+
+        let v = g.get_async().await.unwrap();
+        assert_eq!(TEST_CUSIP, v[0].cusip());
+    }
+
+    #[tokio::test]
+    async fn it_should_look_up_many_cusips() {
+        let mut server = mockito::Server::new_async().await;
+        let client = TreasuryClient::builder()
+            .base_url(server.url())
+            .build()
+            .unwrap();
+
+        let mock = server
+            .mock("GET", TREASURIES_URL)
+            .match_query(Matcher::UrlEncoded("cusip".into(), TEST_CUSIP.into()))
+            .with_body(api_single_item())
+            .expect(2)
+            .create_async()
+            .await;
+
+        let mut batch = GetMany::with_client(&client, [TEST_CUSIP, "x1", TEST_CUSIP]);
+        batch.set_concurrency(2);
+
+        let results = batch.get_async().await;
+
+        mock.assert_async().await;
+        assert_eq!(3, results.len());
+        assert_eq!("x1", results[1].0);
+        assert!(matches!(results[1].1, Err(AuctionResultError::ParseCusip)));
+        assert_eq!(TEST_CUSIP, results[2].1.as_ref().unwrap()[0].cusip());
+    }
+
+    #[test]
+    #[cfg(feature = "blocking")]
+    fn it_should_look_up_many_cusips_on_threads() {
+        let mut server = mockito::Server::new();
+        let client = TreasuryClient::builder()
+            .base_url(server.url())
+            .build()
+            .unwrap();
+
+        server
+            .mock("GET", TREASURIES_URL)
+            .match_query(Matcher::UrlEncoded("cusip".into(), TEST_CUSIP.into()))
+            .with_body(api_single_item())
+            .create();
+
+        let results = GetMany::with_client(&client, ["x1", TEST_CUSIP]).get();
+
+        assert!(results[0].1.is_err());
+        assert_eq!(TEST_CUSIP, results[1].1.as_ref().unwrap()[0].cusip());
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "blocking")]
+    async fn it_should_call_get_within_an_async_runtime() {
+        let mut server = mockito::Server::new_async().await;
+        let client = TreasuryClient::builder()
+            .base_url(server.url())
+            .build()
+            .unwrap();
+
+        let g = Get::with_client(&client, TEST_CUSIP);
+
+        server
+            .mock("GET", TREASURIES_URL)
+            .match_query(Matcher::UrlEncoded("cusip".into(), TEST_CUSIP.into()))
+            .with_body(api_single_item())
+            .create_async()
+            .await;
+
+        let v = g.get().unwrap();
+        assert_eq!(TEST_CUSIP, v[0].cusip());
+    }
 }
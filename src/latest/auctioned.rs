@@ -1,54 +1,38 @@
 //! # Module for retrieving the lastest auction results.
 // #![allow(unused)]
+use chrono::NaiveDate;
+
 use crate::{
-    tenor::Tenor,
-    treasury::{load, security_type::SecurityType, AuctionResult, Treasuries, TreasuryAccess},
+    client::TreasuryClient,
+    tenor::{Tenor, TenorRange},
+    treasury::{
+        decode, load_async, pager::Pager, security_type::SecurityType, AsyncTreasuryAccess,
+        AuctionResult, Treasuries, Treasury, TreasuryAccess,
+    },
 };
 
-#[cfg(not(test))]
-static AUCTIONED_URL: &str = "https://www.treasurydirect.gov/TA_WS/securities/auctioned";
-#[cfg(test)]
+#[cfg(feature = "blocking")]
+use crate::treasury::{load, BlockingTreasuryAccess};
+
+// Use pub(create) for testing puposes.
 pub(crate) static AUCTIONED_URL: &str = "/securities/auctioned";
 
 /// Descriptor of the Latest module.
-#[derive(Debug, Default, PartialEq)]
-pub struct Latest {
+#[derive(Debug)]
+pub struct Latest<'c> {
+    client: &'c TreasuryClient,
     days: usize,
     security_type: SecurityType,
     tenor: Tenor,
-    // #[cfg(test)]
-    host: String,
+    range: Option<TenorRange>,
+    bucketing: bool,
+    limit: Option<usize>,
+    since: Option<NaiveDate>,
 }
 
-impl TreasuryAccess<Treasuries> for Latest {
-    /// Get the latest auction results.
-    /// - If a [`tenor`] is given then only the treasuries with the same tenor will be returned.
-    fn get(&self) -> AuctionResult<Treasuries> {
-        let url = self.url();
-        let response = load(url)?;
-
-        let treasuries: Treasuries = response.json()?;
-        let compare_to = self.tenor.to_string();
-
-        Ok(if self.tenor.is_empty() {
-            treasuries
-        } else {
-            treasuries
-                .into_iter()
-                .filter(|t| {
-                    *t.get_term() == compare_to
-                        || *t.get_security_term() == compare_to
-                        || *t.get_original_security_term() == compare_to
-                })
-                .collect::<Treasuries>()
-        })
-    }
-
+impl TreasuryAccess<Treasuries> for Latest<'_> {
     fn url(&self) -> String {
-        let mut url = String::from(AUCTIONED_URL);
-
-        #[cfg(test)]
-        url.insert_str(0, &self.host);
+        let mut url = format!("{}{}", self.client.base_url(), AUCTIONED_URL);
 
         if self.security_type != SecurityType::Null {
             url.push_str("?type=");
@@ -65,22 +49,92 @@ impl TreasuryAccess<Treasuries> for Latest {
     }
 }
 
-impl Latest {
+#[cfg(feature = "blocking")]
+impl BlockingTreasuryAccess<Treasuries> for Latest<'_> {
+    /// Get the latest auction results.
+    /// - If a [`tenor`] is given then only the treasuries with the same tenor will be returned.
+    /// - If a limit or a date bound is set then the pages of the api are followed.
+    fn get(&self) -> AuctionResult<Treasuries> {
+        let url = self.url();
+        let mut pager = self.pager();
+
+        while let Some(page_url) = pager.next_url(&url) {
+            let body = load(self.client, page_url)?;
+            pager.push(decode(&body)?, |t| self.keep(t));
+        }
+
+        Ok(pager.finish())
+    }
+}
+
+impl AsyncTreasuryAccess<Treasuries> for Latest<'_> {
+    /// Get the latest auction results without blocking the current thread.
+    async fn get_async(&self) -> AuctionResult<Treasuries> {
+        let url = self.url();
+        let mut pager = self.pager();
+
+        while let Some(page_url) = pager.next_url(&url) {
+            let body = load_async(self.client, page_url).await?;
+            pager.push(decode(&body)?, |t| self.keep(t));
+        }
+
+        Ok(pager.finish())
+    }
+}
+
+impl Latest<'static> {
     /// Create a new Latest module from the given security type, ie [`Bond`, `Note`], etc.,
     /// the number of [`days`] to look back and a filter for the tenor, which can be
     /// for example: [`10y`, `10-Y`, `10-years`] or any other specifier of a time
     /// range. If the number of [`days`] is equal to [`0`] then there will be
-    /// only [`250`] records returned.
+    /// only [`250`] records returned, unless a limit or a date bound is set.
+    /// The shared client is used.
     pub fn new(treasury_type: SecurityType, days: usize, tenor: Tenor) -> Self {
+        Self::with_client(TreasuryClient::shared(), treasury_type, days, tenor)
+    }
+}
+
+impl<'c> Latest<'c> {
+    /// Create a new Latest module like [`Latest::new`], using the given client.
+    pub fn with_client(
+        client: &'c TreasuryClient,
+        treasury_type: SecurityType,
+        days: usize,
+        tenor: Tenor,
+    ) -> Self {
         Self {
+            client,
             days,
             security_type: treasury_type,
             tenor,
-            // #[cfg(test)]
-            host: "".to_owned(),
+            range: None,
+            bucketing: false,
+            limit: None,
+            since: None,
         }
     }
 
+    /// Return only the treasuries with a tenor within the range, i. e. 2y..10y.
+    pub fn set_range(&mut self, range: TenorRange) {
+        self.range = Some(range);
+    }
+
+    /// Match the tenor and the range by the benchmark bucket of the remaining
+    /// term, so that a 9-Year 10-Month reopening falls under 10y.
+    pub fn set_bucketing(&mut self, bucketing: bool) {
+        self.bucketing = bucketing;
+    }
+
+    /// Follow the pages of the api until the given number of treasuries is collected.
+    pub fn set_limit(&mut self, limit: usize) {
+        self.limit = Some(limit);
+    }
+
+    /// Follow the pages of the api until the auctions held before the given date are reached.
+    pub fn set_since(&mut self, since: NaiveDate) {
+        self.since = Some(since);
+    }
+
     pub fn get_days(&self) -> usize {
         self.days
     }
@@ -89,9 +143,22 @@ impl Latest {
         self.security_type.to_owned()
     }
 
-    // #[cfg(test)]
-    pub fn set_host(&mut self, host: impl Into<String>) {
-        self.host = host.into()
+    /// Create the pager for the limit and the date bound.
+    fn pager(&self) -> Pager {
+        Pager::new(self.limit, self.since)
+    }
+
+    /// Keep the treasuries of the security type with the tenor or within the range.
+    fn keep(&self, treasury: &Treasury) -> bool {
+        if self.bucketing {
+            self.security_type.matches(treasury)
+                && self.tenor.matches_bucket(treasury)
+                && self.range.as_ref().is_none_or(|range| range.matches_bucket(treasury))
+        } else {
+            self.security_type.matches(treasury)
+                && self.tenor.matches(treasury)
+                && self.range.as_ref().is_none_or(|range| range.matches(treasury))
+        }
     }
 }
 
@@ -99,16 +166,19 @@ impl Latest {
 mod tests {
     use mockito::Matcher;
 
-    use crate::tests::fixture::api_multiple_items;
+    use crate::tests::fixture::{api_many_items, api_multiple_items};
 
     use super::*;
 
     #[test]
+    #[cfg(feature = "blocking")]
     fn it_should_return_the_lastest_auctions() {
         let mut server = mockito::Server::new();
-        let mut latest = Latest::new(SecurityType::Bill, 8, Tenor::default());
-
-        latest.host = server.url();
+        let client = TreasuryClient::builder()
+            .base_url(server.url())
+            .build()
+            .unwrap();
+        let latest = Latest::with_client(&client, SecurityType::Bill, 8, Tenor::default());
 
         server
             .mock("GET", AUCTIONED_URL)
@@ -124,12 +194,15 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "blocking")]
     pub fn it_should_correctly_apply_tenor_filter() {
         let sectype = SecurityType::Null;
         let mut server = mockito::Server::new();
-        let mut latest = Latest::new(sectype.clone(), 8, Tenor::parse("2y").unwrap());
-
-        latest.host = server.url();
+        let client = TreasuryClient::builder()
+            .base_url(server.url())
+            .build()
+            .unwrap();
+        let latest = Latest::with_client(&client, sectype.clone(), 8, Tenor::parse("2y").unwrap());
 
         server
             .mock("GET", AUCTIONED_URL)
@@ -140,4 +213,113 @@ mod tests {
         let response = latest.get().unwrap();
         assert_eq!(2, response.len());
     }
+
+    #[tokio::test]
+    async fn it_should_return_the_lastest_auctions_async() {
+        let mut server = mockito::Server::new_async().await;
+        let client = TreasuryClient::builder()
+            .base_url(server.url())
+            .build()
+            .unwrap();
+        let latest =
+            Latest::with_client(&client, SecurityType::Null, 8, Tenor::parse("2y").unwrap());
+
+        server
+            .mock("GET", AUCTIONED_URL)
+            .match_query(Matcher::UrlEncoded("days".into(), "8".into()))
+            .with_body(api_multiple_items())
+            .create_async()
+            .await;
+
+        let response = latest.get_async().await.unwrap();
+        assert_eq!(2, response.len());
+    }
+
+    #[tokio::test]
+    async fn it_should_apply_a_range_of_tenors() {
+        let mut server = mockito::Server::new_async().await;
+        let client = TreasuryClient::builder()
+            .base_url(server.url())
+            .build()
+            .unwrap();
+        let mut latest = Latest::with_client(&client, SecurityType::Null, 8, Tenor::default());
+        latest.set_range(TenorRange::parse("2y..10y").unwrap());
+
+        // The 2-Year, 5-Year and 7-Year notes and the reopened 2-Year FRN.
+
+        server
+            .mock("GET", AUCTIONED_URL)
+            .match_query(Matcher::UrlEncoded("days".into(), "8".into()))
+            .with_body(api_multiple_items())
+            .create_async()
+            .await;
+
+        let response = latest.get_async().await.unwrap();
+        assert_eq!(4, response.len());
+    }
+
+    #[tokio::test]
+    async fn it_should_match_the_benchmark_buckets() {
+        let mut server = mockito::Server::new_async().await;
+        let client = TreasuryClient::builder()
+            .base_url(server.url())
+            .build()
+            .unwrap();
+
+        server
+            .mock("GET", AUCTIONED_URL)
+            .match_query(Matcher::UrlEncoded("days".into(), "8".into()))
+            .with_body(api_multiple_items())
+            .expect(3)
+            .create_async()
+            .await;
+
+        // The reopened 17-Week bills have a remaining term of 4 and 8 weeks.
+        let mut latest =
+            Latest::with_client(&client, SecurityType::Null, 8, Tenor::parse("17w").unwrap());
+        assert_eq!(3, latest.get_async().await.unwrap().len());
+        latest.set_bucketing(true);
+        assert_eq!(1, latest.get_async().await.unwrap().len());
+
+        // The 42-Day cash management bill falls under 6 weeks.
+        let mut latest =
+            Latest::with_client(&client, SecurityType::Null, 8, Tenor::parse("6w").unwrap());
+        latest.set_bucketing(true);
+        assert_eq!(1, latest.get_async().await.unwrap().len());
+    }
+
+    #[tokio::test]
+    async fn it_should_follow_the_pages_up_to_the_limit() {
+        let mut server = mockito::Server::new_async().await;
+        let client = TreasuryClient::builder()
+            .base_url(server.url())
+            .build()
+            .unwrap();
+        let mut latest =
+            Latest::with_client(&client, SecurityType::Bond, 0, Tenor::default());
+        latest.set_limit(255);
+
+        let first = server
+            .mock("GET", AUCTIONED_URL)
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("pagesize".into(), "250".into()),
+                Matcher::UrlEncoded("pagenum".into(), "0".into()),
+            ]))
+            .with_body(api_many_items())
+            .create_async()
+            .await;
+
+        let second = server
+            .mock("GET", AUCTIONED_URL)
+            .match_query(Matcher::UrlEncoded("pagenum".into(), "1".into()))
+            .with_body(api_multiple_items())
+            .create_async()
+            .await;
+
+        let response = latest.get_async().await.unwrap();
+
+        first.assert_async().await;
+        second.assert_async().await;
+        assert_eq!(255, response.len());
+    }
 }
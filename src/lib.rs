@@ -1,10 +1,16 @@
 /*!
 # The library auctionresult.
 */
+pub mod chain;
+pub mod client;
+pub mod cusip;
 pub mod get;
 pub mod latest;
+pub mod search;
 pub mod tenor;
+pub mod transport;
 pub mod util;
+pub mod when_issued;
 
 #[warn(missing_docs)]
 mod tests;
@@ -14,13 +20,21 @@ pub mod treasury;
 pub mod quality;
 
 // Re-exports - available modules.
-pub use get::Get;
+pub use self::cusip::Cusip;
+pub use chain::Chain;
+pub use client::TreasuryClient;
+pub use get::{Get, GetMany};
 pub use latest::Latest;
+pub use search::Search;
+pub use when_issued::WhenIssued;
 
 // Re-export Treasury Types
 pub use treasury::print::security_vprint;
 pub use treasury::SecurityType;
 
+// Amounts and rates are exact decimals.
+pub use rust_decimal::Decimal;
+
 pub use util::validate_cusip;
 // pub use self::treasury::Treasury;
 // pub use self::treasury::TreasuryAccess;
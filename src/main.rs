@@ -1,6 +1,7 @@
 //! # The Auction Result App
 mod cli;
 use clap::Parser;
+use cli::handle_chain;
 use cli::handle_get;
 use cli::handle_latest;
 #[cfg(feature = "quality")]
@@ -8,7 +9,6 @@ use cli::handle_quality;
 use cli::AuctionResultCommands;
 use cli::AuctionResultParser;
 
-// TODO: Use directories crate for a directory for the cache.
 // TODO: Create a command for upcoming (list the auctions for the next 7 days).
 //#[clap(short = 'a', long = "print-all", conflicts_with = "report")]
 // printall: bool,
@@ -17,18 +17,42 @@ fn main() {
     let args = AuctionResultParser::parse();
 
     match &args.command {
-        AuctionResultCommands::Get { cusip: _ } => {
+        AuctionResultCommands::Get {
+            cusips: _,
+            file: _,
+            concurrency: _,
+            wi: _,
+        } => {
             handle_get(&args);
         }
         AuctionResultCommands::Latest {
             sectype: _,
             days: _,
             tenor: _,
+            bucket: _,
+            limit: _,
+            #[cfg(feature = "quality")]
+            with_quality: _,
+            #[cfg(feature = "quality")]
+            sort_by_score: _,
+            #[cfg(feature = "quality")]
+            lookback: _,
+            #[cfg(feature = "quality")]
+            profile: _,
         } => {
             handle_latest(&args);
         }
+        AuctionResultCommands::Chain { cusip: _, wi: _ } => {
+            handle_chain(&args);
+        }
         #[cfg(feature = "quality")]
-        AuctionResultCommands::Quality { cusip: _, lookback: _ } => {
+        AuctionResultCommands::Quality {
+            cusip: _,
+            lookback: _,
+            wi: _,
+            profile: _,
+            explain: _,
+        } => {
             handle_quality(&args)
         }
     }
@@ -2,12 +2,32 @@
 //!
 //! measures the quality of an auction.
 mod auction_quality;
+mod batch;
+mod contribution;
+mod lookback;
+pub mod profile;
+mod statistics;
 pub use self::auction_quality::AuctionQuality;
+pub use self::batch::{sort_by_score, QualityBatch};
+pub use self::contribution::{Component, Contribution};
+pub use self::lookback::Lookback;
+pub use self::profile::{Profile, Profiles};
+pub use self::statistics::Statistics;
+
+use std::cmp::Reverse;
+
+use rust_decimal::{prelude::ToPrimitive, Decimal};
 
 use crate::tenor::Tenor;
+#[cfg(feature = "blocking")]
+use crate::treasury::BlockingTreasuryAccess;
 use crate::{
-    treasury::{AuctionResult, AuctionResultError, Treasury, TreasuryAccess},
-    Get, Latest,
+    client::TreasuryClient,
+    treasury::{
+        AsyncTreasuryAccess, AuctionResult, AuctionResultError, SecurityType, Treasury,
+        BASIS_POINTS,
+    },
+    Get, Search,
 };
 
 
@@ -19,14 +39,16 @@ const PRIMARY_DEALER_ACCEPTED_WEIGHT: f64 = 0.4;
 const INDIRECT_BIDDER_WEIGHT: f64 = 0.075;
 const DIRECT_BIDDER_WEIGHT: f64 = 0.025;
 
-#[allow(dead_code)]
 const WHEN_ISSUED_WEIGHT: f64 = 0.4;
+const DISCOUNT_MARGIN_WEIGHT: f64 = 0.4;
 
-#[derive(Default, Debug, Clone)]
-pub struct QualityCommand {
+#[derive(Debug, Clone)]
+pub struct QualityCommand<'c> {
+    client: &'c TreasuryClient,
     cusip: String,
-    lookback_auctions: usize,
-    host: String,
+    lookback: Lookback,
+    when_issued_yield: Option<Decimal>,
+    profile: Profile,
     // Info block.
     // treasury: Treasury,
     // bid_to_cover_ratio_prev: f64,
@@ -43,41 +65,71 @@ pub struct QualityCommand {
 // Weighted score (assuming 70% weight for bid-to-cover, 30% for participation):
 // 75 * 0.7 + 85 * 0.3 = 79.5
 
-impl QualityCommand {
-    /// Return a new instance of the quality module.
-    pub fn new(cusip: impl Into<String>, lookback_auctions: usize) -> Self {
+impl QualityCommand<'static> {
+    /// Return a new instance of the quality module, using the shared client.
+    /// The lookback is a number of auctions, zero for the default, or a
+    /// [`Lookback::Window`].
+    pub fn new(cusip: impl Into<String>, lookback: impl Into<Lookback>) -> Self {
+        Self::with_client(TreasuryClient::shared(), cusip, lookback)
+    }
+}
+
+impl<'c> QualityCommand<'c> {
+    /// Return a new instance of the quality module, using the given client.
+    pub fn with_client(
+        client: &'c TreasuryClient,
+        cusip: impl Into<String>,
+        lookback: impl Into<Lookback>,
+    ) -> Self {
         Self {
+            client,
             cusip: cusip.into(),
-            lookback_auctions: if lookback_auctions == 0 {
-                LAST_AUCTIONS
-            } else {
-                lookback_auctions
-            },
-            host: "".to_owned(),
+            lookback: lookback.into(),
+            when_issued_yield: None,
+            profile: Profile::default(),
         }
     }
 
+    /// Weight the components of the quality by the given profile.
+    pub fn set_profile(&mut self, profile: Profile) {
+        self.profile = profile;
+    }
+
+    /// Return the profile the quality is weighted by.
+    pub fn get_profile(&self) -> &Profile {
+        &self.profile
+    }
+
+    /// Set the when-issued yield at the auction, so that the tail is part of the quality.
+    pub fn set_when_issued_yield(&mut self, when_issued_yield: Decimal) {
+        self.when_issued_yield = Some(when_issued_yield);
+    }
+
     /// Return the quality of an auction.
+    #[cfg(feature = "blocking")]
     pub fn calculate(&self) -> AuctionResult<AuctionQuality> {
-        let mut get_command = Get::new(&self.cusip);
+        let treasuries = self.get_command().get()?;
 
-        if cfg!(test) {
-            get_command.set_host(&self.host);
-        }
+        let Some(treasury) = treasuries.into_iter().next() else {
+            return Err(AuctionResultError::EmptyResult);
+        };
 
-        let treasuries = get_command.get()?;
+        let lastest_auctions = self.search_command(&treasury)?.get()?;
 
-        let Some(treasury) = treasuries.first() else {
-            return Err(AuctionResultError::NoTreasury);
-        };
+        self.assess(treasury, &lastest_auctions)
+    }
 
-        let mut aq = AuctionQuality::default();
+    /// Return the quality of an auction without blocking the current thread.
+    pub async fn calculate_async(&self) -> AuctionResult<AuctionQuality> {
+        let treasuries = self.get_command().get_async().await?;
 
-        aq.quality = self.calculate_quality(treasury, &mut aq)?;
-        aq.treasury = treasury.clone();
-        aq.lookback_auctions = self.lookback_auctions;
+        let Some(treasury) = treasuries.into_iter().next() else {
+            return Err(AuctionResultError::EmptyResult);
+        };
 
-        Ok(aq)
+        let lastest_auctions = self.search_command(&treasury)?.get_async().await?;
+
+        self.assess(treasury, &lastest_auctions)
     }
 
     /// Return the CUSIP of the requested auction.
@@ -85,87 +137,149 @@ impl QualityCommand {
         self.cusip.as_str()
     }
 
-    /// Return the number of auctions that are used to calculate the quality of the auction.
+    /// Return the number of auctions that are used to calculate the quality of
+    /// the auction, zero for a window, see [`QualityCommand::get_lookback`].
     pub fn get_number_of_lookback_auctions(&self) -> usize {
-        self.lookback_auctions
+        self.lookback.auctions().unwrap_or_default()
     }
 
-    #[allow(dead_code)]
-    fn set_host(&mut self, host: impl Into<String>) {
-        self.host = host.into();
+    /// Return the auctions to look back.
+    pub fn get_lookback(&self) -> &Lookback {
+        &self.lookback
     }
 
-    /// Return the quality of the auction.
-    //
-    /// [`treasuries`]: The treasuries to consider.
-    /// [`lookback_auctions`]: The _number_ of auctions to consider in the past.
-    fn calculate_quality(&self, treasury: &Treasury, auction_quality: &mut AuctionQuality) -> AuctionResult<f64> {
+    /// Create the get module for the requested auction.
+    fn get_command(&self) -> Get<'c> {
+        Get::with_client(self.client, &self.cusip)
+    }
+
+    /// Create the search module for the given auction and the auctions that
+    /// were held before it, so that auctions of any age can be assessed. Only
+    /// the lookback is fetched: the number of auctions or the days of the window.
+    fn search_command(&self, treasury: &Treasury) -> AuctionResult<Search<'c>> {
         // Get the term of the treasury specified by the given cusip.
         let tenor = Tenor::parse(treasury.get_term())?;
 
         // Get the security type.
         let security_type = treasury.get_security_type();
 
-        // Create the lastest module and search for auctions that were held
-        // before the given auction.
-        let mut latest = Latest::new(security_type, 0, tenor);
+        let mut search = Search::with_client(self.client, security_type, tenor);
 
-        if cfg!(test) {
-            latest.set_host(&self.host);
+        if let Some(auctions) = self.lookback.auctions() {
+            search.set_limit(auctions + 1);
         }
 
-        let lastest_auctions = latest.get()?;
+        if let Some(auction_date) = treasury.get_auction_date() {
+            search.set_until(auction_date.date());
 
-        // Make sure we can look at the lastest X number of auctions.
-        if lastest_auctions.len() < self.lookback_auctions + 1 {
-            return Err(AuctionResultError::OutOfBounds);
+            if let Some(since) = self.lookback.since(auction_date.date()) {
+                search.set_since(since);
+            }
         }
 
-        // Find the auction with given cusip.
-        let Some(pos) = lastest_auctions
-            .iter()
-            .position(|s| s.cusip() == treasury.cusip())
-        else {
-            return Err(AuctionResultError::NoTreasury);
-        };
+        Ok(search)
+    }
 
-        // Make sure we can look behind the lastest X number of auctions.
-        if pos + self.lookback_auctions + 1 > lastest_auctions.len() {
-            return Err(AuctionResultError::OutOfBounds);
+    /// Assess the quality of the given auction against the lastest auctions.
+    fn assess(
+        &self,
+        treasury: Treasury,
+        lastest_auctions: &[Treasury],
+    ) -> AuctionResult<AuctionQuality> {
+        let mut treasury = treasury;
+        if let Some(when_issued_yield) = self.when_issued_yield {
+            treasury.set_when_issued_yield(when_issued_yield);
         }
 
-        let treasuries = lastest_auctions
-            .iter()
-            .skip(pos + 1)
-            .take(self.lookback_auctions)
-            .collect::<Vec<&Treasury>>();
+        let mut aq = AuctionQuality::default();
 
-        self.ratio_mean(&treasuries, auction_quality, self.lookback_auctions);
+        aq.quality = self.calculate_quality(&treasury, lastest_auctions, &mut aq)?;
+        aq.treasury = treasury;
+        aq.lookback = self.lookback.to_owned();
+        aq.profile = self.profile.name().to_owned();
+
+        Ok(aq)
+    }
+
+    /// Return the quality of the auction.
+    //
+    /// [`treasuries`]: The treasuries to consider.
+    /// [`lookback`]: The _number_ of auctions or the window to consider in the past.
+    fn calculate_quality(
+        &self,
+        treasury: &Treasury,
+        lastest_auctions: &[Treasury],
+        auction_quality: &mut AuctionQuality,
+    ) -> AuctionResult<f64> {
+        let treasuries = self.lookback_auctions(treasury, lastest_auctions)?;
+        auction_quality.lookback_auctions = treasuries.len();
+
+        self.ratio_mean(&treasuries, auction_quality, treasuries.len());
+        self.normalize(treasury, &treasuries, auction_quality);
 
         // Capture values.
         // self.treasury = treasury.clone();
         // self.bid_to_cover_ratio = sum_bid_to_cover;
 
-        let diff_primary_dealers = (treasury.get_percentage_debt_purchased_by_dealers()
-            - auction_quality.primary_dealers_prev)
-            * -PRIMARY_DEALER_ACCEPTED_WEIGHT;
-
-        let diff_direct_bidders = (treasury.get_percentage_debt_purchased_by_directs()
-            - auction_quality.direct_bidders_prev)
-            * -DIRECT_BIDDER_WEIGHT;
-
-        let diff_indirect_bidders = (treasury.get_percentage_debt_purchased_by_indirects()
-            - auction_quality.indirect_bidders_prev)
-            * -INDIRECT_BIDDER_WEIGHT;
+        let profile = &self.profile;
+
+        auction_quality.contributions = vec![
+            Contribution::new(
+                Component::BidToCover,
+                treasury.get_bid_to_cover_ratio(),
+                auction_quality.bid_to_cover_ratio_prev,
+                profile.bid_to_cover(),
+            ),
+            Contribution::new(
+                Component::Dealers,
+                treasury.get_percentage_debt_purchased_by_dealers(),
+                auction_quality.primary_dealers_prev,
+                profile.primary_dealers(),
+            ),
+            Contribution::new(
+                Component::Directs,
+                treasury.get_percentage_debt_purchased_by_directs(),
+                auction_quality.direct_bidders_prev,
+                profile.direct_bidders(),
+            ),
+            Contribution::new(
+                Component::Indirects,
+                treasury.get_percentage_debt_purchased_by_indirects(),
+                auction_quality.indirect_bidders_prev,
+                profile.indirect_bidders(),
+            ),
+        ];
+
+        // A tail lowers the quality, a stop-through raises it.
+        if let Some(tail) = treasury.get_tail() {
+            auction_quality.contributions.push(Contribution::new(
+                Component::Tail,
+                Some(tail),
+                Some(Decimal::ZERO),
+                profile.when_issued(),
+            ));
+        }
 
-        let diff_bid_to_cover =
-            (treasury.get_bid_to_cover_ratio() - auction_quality.bid_to_cover_ratio_prev) * BID_TO_COVER_RATIO_WEIGHT;
+        // FRNs have no yield, a higher discount margin than before lowers the
+        // quality by basis point.
+        if treasury.get_security_type() == SecurityType::Frn {
+            let basis_points =
+                |rate: Option<Decimal>| rate.and_then(|r| r.checked_mul(BASIS_POINTS));
+
+            auction_quality.contributions.push(Contribution::new(
+                Component::DiscountMargin,
+                basis_points(treasury.get_auction_rate()),
+                basis_points(auction_quality.auction_rate_prev),
+                profile.discount_margin(),
+            ));
+        }
 
         // Round to 3 decimal places.
-        let quality = ((diff_primary_dealers
-            + diff_direct_bidders
-            + diff_indirect_bidders
-            + diff_bid_to_cover)
+        let quality = (auction_quality
+            .contributions
+            .iter()
+            .map(Contribution::contribution)
+            .sum::<f64>()
             * 1000.0)
             .round()
             / 1000.0;
@@ -173,43 +287,181 @@ impl QualityCommand {
         Ok(quality)
     }
 
+    /// Express the components as z-scores and percentile ranks against the
+    /// lookback auctions and score the weighted z-scores from 0 to 100. The
+    /// tail has no history and is only part of the legacy quality.
+    fn normalize(
+        &self,
+        treasury: &Treasury,
+        treasuries: &[&Treasury],
+        auction_quality: &mut AuctionQuality,
+    ) {
+        auction_quality.bid_to_cover_ratio_statistics =
+            statistics(treasury, treasuries, Treasury::get_bid_to_cover_ratio);
+        auction_quality.primary_dealers_statistics =
+            statistics(treasury, treasuries, Treasury::get_percentage_debt_purchased_by_dealers);
+        auction_quality.direct_bidders_statistics =
+            statistics(treasury, treasuries, Treasury::get_percentage_debt_purchased_by_directs);
+        auction_quality.indirect_bidders_statistics =
+            statistics(treasury, treasuries, Treasury::get_percentage_debt_purchased_by_indirects);
+        auction_quality.auction_rate_statistics =
+            statistics(treasury, treasuries, Treasury::get_auction_rate);
+
+        let mut weighted = vec![
+            (
+                self.profile.bid_to_cover(),
+                auction_quality.bid_to_cover_ratio_statistics.z_score(),
+            ),
+            (
+                self.profile.primary_dealers(),
+                auction_quality.primary_dealers_statistics.z_score(),
+            ),
+            (
+                self.profile.direct_bidders(),
+                auction_quality.direct_bidders_statistics.z_score(),
+            ),
+            (
+                self.profile.indirect_bidders(),
+                auction_quality.indirect_bidders_statistics.z_score(),
+            ),
+        ];
+
+        if treasury.get_security_type() == SecurityType::Frn {
+            weighted.push((
+                self.profile.discount_margin(),
+                auction_quality.auction_rate_statistics.z_score(),
+            ));
+        }
+
+        auction_quality.score = statistics::score(&weighted);
+    }
+
+    /// Return the auctions to look back from the lastest auctions, the given
+    /// auction first: the number of auctions after it or the auctions in the
+    /// window before it.
+    fn lookback_auctions<'t>(
+        &self,
+        treasury: &Treasury,
+        lastest_auctions: &'t [Treasury],
+    ) -> AuctionResult<Vec<&'t Treasury>> {
+        let required = self.lookback.auctions().unwrap_or(1);
+
+        // Make sure we can look at the lastest X number of auctions.
+        if lastest_auctions.len() < required + 1 {
+            return Err(AuctionResultError::InsufficientHistory {
+                required,
+                available: lastest_auctions.len().saturating_sub(1),
+            });
+        }
+
+        // Order the auctions from the newest to the oldest, whatever order
+        // they were loaded in.
+        let mut lastest_auctions = lastest_auctions.iter().collect::<Vec<&'t Treasury>>();
+        lastest_auctions.sort_by_key(|t| Reverse(t.get_auction_date()));
+
+        // Find the auction with given cusip. Without it no auction is known
+        // to be held before it.
+        let Some(pos) = lastest_auctions
+            .iter()
+            .position(|s| s.cusip() == treasury.cusip())
+        else {
+            return Err(AuctionResultError::InsufficientHistory {
+                required,
+                available: 0,
+            });
+        };
+
+        let since = treasury
+            .get_auction_date()
+            .and_then(|auction_date| self.lookback.since(auction_date.date()));
+
+        let treasuries = lastest_auctions
+            .into_iter()
+            .skip(pos + 1)
+            .take_while(|s| match (since, s.get_auction_date()) {
+                (Some(since), Some(auction_date)) => auction_date.date() >= since,
+                _ => true,
+            })
+            .take(self.lookback.auctions().unwrap_or(usize::MAX))
+            .collect::<Vec<&Treasury>>();
+
+        // Make sure we can look behind the lastest X number of auctions.
+        if treasuries.len() < required {
+            return Err(AuctionResultError::InsufficientHistory {
+                required,
+                available: treasuries.len(),
+            });
+        }
+
+        Ok(treasuries)
+    }
+
     /// Calculate the mean of the _bid to cover_, _primary dealers_ and _indirect_bidders_ of
     /// the last [`last_auctions`] auctions.
-    fn ratio_mean(&self, treasuries: &[&Treasury], auction_quality: &mut AuctionQuality, last_auctions: usize) {
-        let (sum_primary_dealers, sum_direct_bidders, sum_bid_to_cover, sum_indirect_bidders) =
-            treasuries.iter().take(last_auctions).fold(
-                (0., 0., 0., 0.),
-                |(
-                    sum_primary_dealers,
-                    sum_direct_bidders,
-                    sum_bid_to_cover,
-                    sum_indirect_bidders,
-                ),
-                 treasury| {
-                    (
-                        sum_primary_dealers + treasury.get_percentage_debt_purchased_by_dealers(),
-                        sum_direct_bidders + treasury.get_percentage_debt_purchased_by_directs(),
-                        sum_bid_to_cover + treasury.get_bid_to_cover_ratio(),
-                        sum_indirect_bidders + treasury.get_percentage_debt_purchased_by_indirects(),
-                    )
-                },
-            );
-
-        auction_quality.primary_dealers_prev = sum_primary_dealers / last_auctions as f64;
-        auction_quality.direct_bidders_prev = sum_direct_bidders / last_auctions as f64;
-        auction_quality.indirect_bidders_prev = sum_indirect_bidders / last_auctions as f64;
-        auction_quality.bid_to_cover_ratio_prev = sum_bid_to_cover / last_auctions as f64;
+    fn ratio_mean(
+        &self,
+        treasuries: &[&Treasury],
+        auction_quality: &mut AuctionQuality,
+        last_auctions: usize,
+    ) {
+        let treasuries = &treasuries[..last_auctions.min(treasuries.len())];
+
+        auction_quality.primary_dealers_prev =
+            mean(treasuries, Treasury::get_percentage_debt_purchased_by_dealers);
+        auction_quality.direct_bidders_prev =
+            mean(treasuries, Treasury::get_percentage_debt_purchased_by_directs);
+        auction_quality.indirect_bidders_prev =
+            mean(treasuries, Treasury::get_percentage_debt_purchased_by_indirects);
+        auction_quality.bid_to_cover_ratio_prev =
+            mean(treasuries, Treasury::get_bid_to_cover_ratio);
+
+        // FRNs are compared by the discount margin instead of the yield.
+        auction_quality.auction_rate_prev = mean(treasuries, Treasury::get_auction_rate);
     }
 }
 
+/// Return the statistics of a value of the treasury against the lookback auctions.
+fn statistics(
+    treasury: &Treasury,
+    treasuries: &[&Treasury],
+    value: impl Fn(&Treasury) -> Option<Decimal>,
+) -> Statistics {
+    let lookback = treasuries
+        .iter()
+        .filter_map(|treasury| value(treasury).and_then(|v| v.to_f64()))
+        .collect::<Vec<f64>>();
+
+    Statistics::new(value(treasury).and_then(|v| v.to_f64()), &lookback)
+}
+
+/// Return the exact mean of a value of the treasuries, skipping the treasuries
+/// without the value. [`None`] if no treasury has the value.
+fn mean(
+    treasuries: &[&Treasury],
+    value: impl Fn(&Treasury) -> Option<Decimal>,
+) -> Option<Decimal> {
+    let values = treasuries
+        .iter()
+        .filter_map(|treasury| value(treasury))
+        .collect::<Vec<Decimal>>();
+
+    values
+        .iter()
+        .sum::<Decimal>()
+        .checked_div(Decimal::from(values.len()))
+}
+
 #[cfg(test)]
 mod tests {
     use mockito::Matcher;
 
     use crate::{
         get::TREASURIES_URL,
-        latest::auctioned::AUCTIONED_URL,
-        tests::fixture::{api_30y_bond_item, api_30y_very_old_bond_item, api_many_items},
+        tests::fixture::{
+            api_30y_bond_item, api_30y_tips_item, api_30y_very_old_bond_item, api_frn_items,
+            api_many_items, api_single_item,
+        },
+        treasury::Treasuries,
     };
 
     use super::*;
@@ -218,11 +470,15 @@ mod tests {
     const TEST_CUSIP: &str = "912810SH2";
 
     #[test]
+    #[cfg(feature = "blocking")]
     fn it_should_assess_the_quality_of_an_auction() {
         let mut server = mockito::Server::new();
 
-        let mut quality = QualityCommand::new(TEST_CUSIP, LAST_AUCTIONS);
-        quality.set_host(server.url());
+        let client = TreasuryClient::builder()
+            .base_url(server.url())
+            .build()
+            .unwrap();
+        let quality = QualityCommand::with_client(&client, TEST_CUSIP, LAST_AUCTIONS);
 
         // println!("{:#?}", server.url());
 
@@ -236,7 +492,7 @@ mod tests {
             .create();
 
         server
-            .mock("GET", AUCTIONED_URL)
+            .mock("GET", TREASURIES_URL)
             .match_query(Matcher::AllOf(vec![Matcher::UrlEncoded(
                 "type".into(),
                 "Bond".into(),
@@ -250,11 +506,15 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "blocking")]
     fn it_should_correctly_handle_out_of_bound_conditions() {
         let mut server = mockito::Server::new();
 
-        let mut quality = QualityCommand::new(OUT_OF_BOUND_CUSIP, LAST_AUCTIONS);
-        quality.set_host(server.url());
+        let client = TreasuryClient::builder()
+            .base_url(server.url())
+            .build()
+            .unwrap();
+        let quality = QualityCommand::with_client(&client, OUT_OF_BOUND_CUSIP, LAST_AUCTIONS);
 
         server
             .mock("GET", TREASURIES_URL)
@@ -266,7 +526,7 @@ mod tests {
             .create();
 
         server
-            .mock("GET", AUCTIONED_URL)
+            .mock("GET", TREASURIES_URL)
             .match_query(Matcher::AllOf(vec![Matcher::UrlEncoded(
                 "type".into(),
                 "Bond".into(),
@@ -274,8 +534,308 @@ mod tests {
             .with_body(api_many_items())
             .create();
 
+        // The bonds reach back to a single auction before the very old bond,
+        // too few to look back at the last five auctions.
         let auction_quality = quality.calculate();
 
-        assert!(auction_quality.is_err());
+        assert!(matches!(
+            auction_quality,
+            Err(AuctionResultError::InsufficientHistory { required: 5, .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn it_should_correctly_handle_out_of_bound_conditions_async() {
+        let mut server = mockito::Server::new_async().await;
+
+        let client = TreasuryClient::builder()
+            .base_url(server.url())
+            .build()
+            .unwrap();
+        let quality = QualityCommand::with_client(&client, OUT_OF_BOUND_CUSIP, LAST_AUCTIONS);
+
+        server
+            .mock("GET", TREASURIES_URL)
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("cusip".into(), quality.cusip().into()),
+                Matcher::UrlEncoded("format".into(), "json".into()),
+            ]))
+            .with_body(api_30y_very_old_bond_item())
+            .create_async()
+            .await;
+
+        server
+            .mock("GET", TREASURIES_URL)
+            .match_query(Matcher::AllOf(vec![Matcher::UrlEncoded(
+                "type".into(),
+                "Bond".into(),
+            )]))
+            .with_body(api_many_items())
+            .create_async()
+            .await;
+
+        // The bonds reach back to a single auction before the very old bond,
+        // too few to look back at the last five auctions.
+        let auction_quality = quality.calculate_async().await;
+
+        assert!(matches!(
+            auction_quality,
+            Err(AuctionResultError::InsufficientHistory { required: 5, .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn it_should_assess_the_quality_of_an_auction_async() {
+        let mut server = mockito::Server::new_async().await;
+
+        let client = TreasuryClient::builder()
+            .base_url(server.url())
+            .build()
+            .unwrap();
+        let quality = QualityCommand::with_client(&client, TEST_CUSIP, LAST_AUCTIONS);
+
+        server
+            .mock("GET", TREASURIES_URL)
+            .match_query(Matcher::UrlEncoded("cusip".into(), TEST_CUSIP.into()))
+            .with_body(api_30y_bond_item())
+            .create_async()
+            .await;
+
+        server
+            .mock("GET", TREASURIES_URL)
+            .match_query(Matcher::UrlEncoded("type".into(), "Bond".into()))
+            .with_body(api_many_items())
+            .create_async()
+            .await;
+
+        let auction_quality = quality.calculate_async().await.unwrap();
+
+        assert_eq!(-2.127, auction_quality.get());
+
+        // The bid to cover ratio is the lowest and the dealers took the most
+        // of the lookback auctions, which scores at the bottom.
+        assert_eq!(Some(0.0), auction_quality.get_score());
+        assert_eq!(Some(0.0), auction_quality.get_bid_to_cover_statistics().percentile());
+        assert_eq!(Some(100.0), auction_quality.get_dealers_statistics().percentile());
+
+        // The contributions of the components add up to the quality.
+        let contributions = auction_quality.get_contributions();
+        let sum = contributions.iter().map(Contribution::contribution).sum::<f64>();
+        assert_eq!(4, contributions.len());
+        assert_eq!(-2.127, (sum * 1000.0).round() / 1000.0);
+    }
+
+    #[test]
+    fn it_should_assess_the_auctions_in_the_order_they_were_held() {
+        let quality = QualityCommand::new(TEST_CUSIP, LAST_AUCTIONS);
+
+        let treasuries: Treasuries = serde_json::from_str(api_30y_bond_item()).unwrap();
+        let mut oldest_first: Treasuries = serde_json::from_str(api_many_items()).unwrap();
+        oldest_first.reverse();
+
+        let auction_quality = quality
+            .assess(treasuries[0].clone(), &oldest_first)
+            .unwrap();
+
+        assert_eq!(-2.127, auction_quality.get());
+    }
+
+    #[test]
+    fn it_should_not_assess_an_auction_missing_from_its_history() {
+        let quality = QualityCommand::new("91282CJQ5", LAST_AUCTIONS);
+
+        // A note, which is not part of the history of the bonds.
+        let treasuries: Treasuries = serde_json::from_str(api_single_item()).unwrap();
+        let history: Treasuries = serde_json::from_str(api_many_items()).unwrap();
+
+        let auction_quality = quality.assess(treasuries[0].clone(), &history);
+
+        assert!(matches!(
+            auction_quality,
+            Err(AuctionResultError::InsufficientHistory { available: 0, .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn it_should_lower_the_quality_by_the_tail() {
+        let mut server = mockito::Server::new_async().await;
+
+        let client = TreasuryClient::builder()
+            .base_url(server.url())
+            .build()
+            .unwrap();
+        let mut quality = QualityCommand::with_client(&client, TEST_CUSIP, LAST_AUCTIONS);
+        quality.set_when_issued_yield(Decimal::new(2624, 3));
+
+        server
+            .mock("GET", TREASURIES_URL)
+            .match_query(Matcher::UrlEncoded("cusip".into(), TEST_CUSIP.into()))
+            .with_body(api_30y_bond_item())
+            .create_async()
+            .await;
+
+        server
+            .mock("GET", TREASURIES_URL)
+            .match_query(Matcher::UrlEncoded("type".into(), "Bond".into()))
+            .with_body(api_many_items())
+            .create_async()
+            .await;
+
+        let auction_quality = quality.calculate_async().await.unwrap();
+
+        // The auction tailed by 2 basis points.
+        assert_eq!(Some(Decimal::new(2, 0)), auction_quality.get_tail());
+        assert_eq!(
+            Some(Component::Tail),
+            auction_quality.get_contributions().last().map(Contribution::component)
+        );
+        assert_eq!(-2.927, auction_quality.get());
+    }
+
+    #[tokio::test]
+    async fn it_should_weight_the_quality_by_the_profile() {
+        let mut server = mockito::Server::new_async().await;
+
+        let client = TreasuryClient::builder()
+            .base_url(server.url())
+            .build()
+            .unwrap();
+        let mut quality = QualityCommand::with_client(&client, TEST_CUSIP, LAST_AUCTIONS);
+        quality.set_profile(Profile::foreign_demand());
+
+        server
+            .mock("GET", TREASURIES_URL)
+            .match_query(Matcher::UrlEncoded("cusip".into(), TEST_CUSIP.into()))
+            .with_body(api_30y_bond_item())
+            .create_async()
+            .await;
+
+        server
+            .mock("GET", TREASURIES_URL)
+            .match_query(Matcher::UrlEncoded("type".into(), "Bond".into()))
+            .with_body(api_many_items())
+            .create_async()
+            .await;
+
+        let auction_quality = quality.calculate_async().await.unwrap();
+
+        assert_eq!("foreign-demand", auction_quality.get_profile());
+        assert_eq!(-4.813, auction_quality.get());
+    }
+
+    #[tokio::test]
+    async fn it_should_compare_tips_only_against_tips() {
+        let mut server = mockito::Server::new_async().await;
+
+        let client = TreasuryClient::builder()
+            .base_url(server.url())
+            .build()
+            .unwrap();
+        let quality = QualityCommand::with_client(&client, TEST_CUSIP, LAST_AUCTIONS);
+
+        server
+            .mock("GET", TREASURIES_URL)
+            .match_query(Matcher::UrlEncoded("cusip".into(), TEST_CUSIP.into()))
+            .with_body(api_30y_tips_item())
+            .create_async()
+            .await;
+
+        let search = server
+            .mock("GET", TREASURIES_URL)
+            .match_query(Matcher::UrlEncoded("type".into(), "TIPS".into()))
+            .with_body(api_many_items())
+            .expect_at_least(1)
+            .create_async()
+            .await;
+
+        let auction_quality = quality.calculate_async().await;
+
+        // The nominal bonds of the response must not be used.
+        search.assert_async().await;
+        assert!(matches!(
+            auction_quality,
+            Err(AuctionResultError::InsufficientHistory { available: 0, .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn it_should_compare_the_discount_margin_of_frns() {
+        let mut server = mockito::Server::new_async().await;
+
+        let client = TreasuryClient::builder()
+            .base_url(server.url())
+            .build()
+            .unwrap();
+        let quality = QualityCommand::with_client(&client, "91282CJD4", 3);
+
+        server
+            .mock("GET", TREASURIES_URL)
+            .match_query(Matcher::UrlEncoded("cusip".into(), "91282CJD4".into()))
+            .with_body(api_frn_items())
+            .create_async()
+            .await;
+
+        server
+            .mock("GET", TREASURIES_URL)
+            .match_query(Matcher::UrlEncoded("type".into(), "FRN".into()))
+            .with_body(api_frn_items())
+            .create_async()
+            .await;
+
+        let auction_quality = quality.calculate_async().await.unwrap();
+
+        // The margin of 25 bp is 3 bp above the mean of 22 bp, everything else is unchanged.
+        assert_eq!(Some(Decimal::new(22, 2)), auction_quality.get_auction_rate_mean());
+        assert_eq!(
+            Some(Component::DiscountMargin),
+            auction_quality.get_contributions().last().map(Contribution::component)
+        );
+        assert_eq!(-1.2, auction_quality.get());
+
+        // 1.5 standard deviations above the mean.
+        assert_eq!(Some(100.0), auction_quality.get_auction_rate_statistics().percentile());
+        assert_eq!(Some(6.7), auction_quality.get_score());
+    }
+
+    #[tokio::test]
+    async fn it_should_look_back_a_calendar_window() {
+        let mut server = mockito::Server::new_async().await;
+
+        let client = TreasuryClient::builder()
+            .base_url(server.url())
+            .build()
+            .unwrap();
+        let lookback = Lookback::parse("6m").unwrap();
+        let quality = QualityCommand::with_client(&client, "912810TX6", lookback);
+
+        server
+            .mock("GET", TREASURIES_URL)
+            .match_query(Matcher::UrlEncoded("cusip".into(), "912810TX6".into()))
+            .with_body(api_many_items())
+            .create_async()
+            .await;
+
+        // Only the window before the auction of 2024-02-08 is searched.
+        let search = server
+            .mock("GET", TREASURIES_URL)
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("type".into(), "Bond".into()),
+                Matcher::UrlEncoded("startDate".into(), "2023-08-08".into()),
+                Matcher::UrlEncoded("endDate".into(), "2024-02-08".into()),
+            ]))
+            .with_body(api_many_items())
+            .expect(1)
+            .create_async()
+            .await;
+
+        let auction_quality = quality.calculate_async().await.unwrap();
+
+        search.assert_async().await;
+        assert_eq!(
+            &Lookback::Window(Tenor::parse("6m").unwrap()),
+            auction_quality.get_lookback()
+        );
+        // The 30-year auctions from 2023-08-10 to 2024-01-11.
+        assert_eq!(6, auction_quality.get_number_of_lookback_auctions());
     }
 }
@@ -1,16 +1,32 @@
 //! The result
 
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
 use crate::treasury::Treasury;
 
-#[derive(Default, Debug)]
+use super::{Contribution, Lookback, Statistics};
+
+#[derive(Default, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct AuctionQuality {
     pub (in crate::quality) treasury: Treasury,
+    pub (in crate::quality) lookback: Lookback,
     pub (in crate::quality) lookback_auctions: usize,
-    pub (in crate::quality) bid_to_cover_ratio_prev: f64,
-    pub (in crate::quality) primary_dealers_prev: f64,
-    pub (in crate::quality) direct_bidders_prev: f64,
-    pub (in crate::quality) indirect_bidders_prev: f64,
+    pub (in crate::quality) bid_to_cover_ratio_prev: Option<Decimal>,
+    pub (in crate::quality) primary_dealers_prev: Option<Decimal>,
+    pub (in crate::quality) direct_bidders_prev: Option<Decimal>,
+    pub (in crate::quality) indirect_bidders_prev: Option<Decimal>,
+    pub (in crate::quality) auction_rate_prev: Option<Decimal>,
     pub (in crate::quality) quality: f64,
+    pub (in crate::quality) profile: String,
+    pub (in crate::quality) bid_to_cover_ratio_statistics: Statistics,
+    pub (in crate::quality) primary_dealers_statistics: Statistics,
+    pub (in crate::quality) direct_bidders_statistics: Statistics,
+    pub (in crate::quality) indirect_bidders_statistics: Statistics,
+    pub (in crate::quality) auction_rate_statistics: Statistics,
+    pub (in crate::quality) score: Option<f64>,
+    pub (in crate::quality) contributions: Vec<Contribution>,
 }
 
 impl AuctionQuality {
@@ -20,25 +36,41 @@ impl AuctionQuality {
     }
 
     /// Get the bid to cover ratio of this [`Auction`].
-    pub fn get_bid_to_cover_ratio(&self) -> f64 {
+    pub fn get_bid_to_cover_ratio(&self) -> Option<Decimal> {
         self.bid_to_cover_ratio_prev
     }
 
     /// Get the percentage of debt that was accepted by primary dealers for this [`Auction`].
-    pub fn get_percentage_debt_purchased_by_dealers(&self) -> f64 {
+    pub fn get_percentage_debt_purchased_by_dealers(&self) -> Option<Decimal> {
         self.primary_dealers_prev
     }
 
     /// Returns the get percentage debt purchased by directs of this [`Auction`].
-    pub fn get_percentage_debt_purchased_by_directs(&self) -> f64 {
+    pub fn get_percentage_debt_purchased_by_directs(&self) -> Option<Decimal> {
         self.direct_bidders_prev
     }
 
     /// Returns the get percentage debt purchased by indirects of this [`Auction`].
-    pub fn get_percentage_debt_purchased_by_indirects(&self) -> f64 {
+    pub fn get_percentage_debt_purchased_by_indirects(&self) -> Option<Decimal> {
         self.indirect_bidders_prev
     }
 
+    /// Return the mean rate the previous auctions cleared at, the discount margin for FRNs.
+    pub fn get_auction_rate_mean(&self) -> Option<Decimal> {
+        self.auction_rate_prev
+    }
+
+    /// Return the tail in basis points, if a when-issued yield was supplied.
+    pub fn get_tail(&self) -> Option<Decimal> {
+        self.treasury.get_tail()
+    }
+
+    /// Return the mean rate the previous auctions cleared at.
+    #[deprecated(note = "use `get_auction_rate_mean` instead")]
+    pub fn get_auction_rate(&self) -> Option<Decimal> {
+        self.get_auction_rate_mean()
+    }
+
     /// Return the stored treasury.
     pub fn get_treasury(&self) -> Treasury {
         self.treasury.to_owned()
@@ -48,8 +80,56 @@ impl AuctionQuality {
         self.quality
     }
 
+    /// Return the z-score and the percentile rank of the bid to cover ratio.
+    pub fn get_bid_to_cover_statistics(&self) -> Statistics {
+        self.bid_to_cover_ratio_statistics
+    }
+
+    /// Return the z-score and the percentile rank of the percentage accepted by primary dealers.
+    pub fn get_dealers_statistics(&self) -> Statistics {
+        self.primary_dealers_statistics
+    }
+
+    /// Return the z-score and the percentile rank of the percentage accepted by direct bidders.
+    pub fn get_directs_statistics(&self) -> Statistics {
+        self.direct_bidders_statistics
+    }
+
+    /// Return the z-score and the percentile rank of the percentage accepted by indirect bidders.
+    pub fn get_indirects_statistics(&self) -> Statistics {
+        self.indirect_bidders_statistics
+    }
+
+    /// Return the z-score and the percentile rank of the rate the auction
+    /// cleared at, the discount margin for FRNs.
+    pub fn get_auction_rate_statistics(&self) -> Statistics {
+        self.auction_rate_statistics
+    }
+
+    /// Return the normalized score from 0 to 100, 50 is an average auction.
+    /// Unlike the legacy quality it can be compared across tenors.
+    pub fn get_score(&self) -> Option<f64> {
+        self.score
+    }
+
+    /// Return the deltas of the components and how much they moved the
+    /// quality. The tail is only a component if a when-issued yield was supplied.
+    pub fn get_contributions(&self) -> &[Contribution] {
+        &self.contributions
+    }
+
+    /// Return the name of the profile that weighted the quality.
+    pub fn get_profile(&self) -> &str {
+        self.profile.as_str()
+    }
+
     /// Return the number of auctions that are used to calculate the quality of the auction.
     pub fn get_number_of_lookback_auctions(&self) -> usize {
         self.lookback_auctions
     }
+
+    /// Return the auctions looked back, a number of auctions or a window.
+    pub fn get_lookback(&self) -> &Lookback {
+        &self.lookback
+    }
 }
\ No newline at end of file
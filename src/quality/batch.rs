@@ -0,0 +1,347 @@
+//! The quality of many auctions
+//!
+//! Every auction is scored against the auctions of the same security type and
+//! tenor before it. The history of a security type is searched once for all
+//! of its auctions, reaching back only as far as the lookback of its tenors needs.
+use chrono::{Duration, NaiveDate};
+
+#[cfg(feature = "blocking")]
+use crate::treasury::BlockingTreasuryAccess;
+use crate::{
+    client::TreasuryClient,
+    tenor::Tenor,
+    treasury::{AsyncTreasuryAccess, AuctionResult, SecurityType, Treasury},
+    when_issued::WhenIssued,
+    Search,
+};
+
+use super::{AuctionQuality, Lookback, Profile, QualityCommand};
+
+/// The longest time between two auctions of a tenor: the 30-year TIPS are
+/// auctioned twice a year.
+const WEEKS_BETWEEN_AUCTIONS: i64 = 26;
+
+/// The longest time between two auctions of a bill: the 52-week bills are
+/// auctioned every four weeks, the others weekly.
+const WEEKS_BETWEEN_BILLS: i64 = 4;
+
+/// The longest time between two auctions of a note, a bond or a FRN, which are
+/// auctioned or reopened every month.
+const WEEKS_BETWEEN_COUPONS: i64 = 5;
+
+/// Descriptor of the batch of quality commands.
+#[derive(Debug, Clone)]
+pub struct QualityBatch<'c> {
+    client: &'c TreasuryClient,
+    lookback: Lookback,
+    profile: Profile,
+    when_issued: WhenIssued,
+}
+
+impl QualityBatch<'static> {
+    /// Return a new batch that looks back the given number of auctions or
+    /// window, using the shared client.
+    pub fn new(lookback: impl Into<Lookback>) -> Self {
+        Self::with_client(TreasuryClient::shared(), lookback)
+    }
+}
+
+impl<'c> QualityBatch<'c> {
+    /// Return a new batch like [`QualityBatch::new`], using the given client.
+    pub fn with_client(client: &'c TreasuryClient, lookback: impl Into<Lookback>) -> Self {
+        Self {
+            client,
+            lookback: lookback.into(),
+            profile: Profile::default(),
+            when_issued: WhenIssued::default(),
+        }
+    }
+
+    /// Weight the components of the qualities by the given profile.
+    pub fn set_profile(&mut self, profile: Profile) {
+        self.profile = profile;
+    }
+
+    /// Set the when-issued yields, so that the tails are part of the qualities.
+    pub fn set_when_issued(&mut self, when_issued: WhenIssued) {
+        self.when_issued = when_issued;
+    }
+
+    /// Return the qualities of the auctions, searching the history of every
+    /// security type once.
+    #[cfg(feature = "blocking")]
+    pub fn calculate(
+        &self,
+        auctions: &[Treasury],
+    ) -> AuctionResult<Vec<AuctionResult<AuctionQuality>>> {
+        let mut history = Vec::new();
+
+        for search in self.search_commands(auctions) {
+            history.extend(search.get()?);
+        }
+
+        Ok(self.assess(auctions, &history))
+    }
+
+    /// Return the qualities of the auctions without blocking the current thread.
+    pub async fn calculate_async(
+        &self,
+        auctions: &[Treasury],
+    ) -> AuctionResult<Vec<AuctionResult<AuctionQuality>>> {
+        let mut history = Vec::new();
+
+        for search in self.search_commands(auctions) {
+            history.extend(search.get_async().await?);
+        }
+
+        Ok(self.assess(auctions, &history))
+    }
+
+    /// Score every auction against the auctions of the same security type and
+    /// tenor that were held before it. The results are in the order of the auctions.
+    pub fn assess(
+        &self,
+        auctions: &[Treasury],
+        history: &[Treasury],
+    ) -> Vec<AuctionResult<AuctionQuality>> {
+        auctions
+            .iter()
+            .map(|auction| {
+                let mut command = QualityCommand::with_client(
+                    self.client,
+                    auction.cusip(),
+                    self.lookback.to_owned(),
+                );
+                command.set_profile(self.profile.to_owned());
+
+                if let Some(when_issued_yield) = self.when_issued.get(auction.get_cusip()) {
+                    command.set_when_issued_yield(when_issued_yield);
+                }
+
+                command.assess(auction.to_owned(), &predecessors(auction, history)?)
+            })
+            .collect()
+    }
+
+    /// Create a search for the history of every security type of the auctions,
+    /// reaching back to the window or far enough for the number of auctions at
+    /// the frequency of the tenors of the security type.
+    fn search_commands(&self, auctions: &[Treasury]) -> Vec<Search<'c>> {
+        let mut groups: Vec<(SecurityType, Vec<NaiveDate>)> = Vec::new();
+
+        for auction in auctions {
+            let Some(auction_date) = auction.get_auction_date() else {
+                continue;
+            };
+            let security_type = auction.get_security_type();
+
+            match groups.iter_mut().find(|(t, _)| *t == security_type) {
+                Some((_, dates)) => dates.push(auction_date.date()),
+                None => groups.push((security_type, vec![auction_date.date()])),
+            }
+        }
+
+        groups
+            .into_iter()
+            .filter_map(|(security_type, dates)| {
+                let until = *dates.iter().max()?;
+                let earliest = *dates.iter().min()?;
+                let since = match &self.lookback {
+                    Lookback::Auctions(auctions) => {
+                        since_auctions(&security_type, earliest, *auctions)
+                    }
+                    Lookback::Window(_) => self.lookback.since(earliest),
+                };
+
+                let mut search = Search::with_client(self.client, security_type, Tenor::default());
+                // A lookback before the first representable date searches the whole history.
+                if let Some(since) = since {
+                    search.set_since(since);
+                }
+                search.set_until(until);
+
+                Some(search)
+            })
+            .collect()
+    }
+}
+
+/// Return the first day far enough before the date for the number of auctions
+/// of a tenor of the security type, [`None`] if no date is that far back.
+fn since_auctions(
+    security_type: &SecurityType,
+    date: NaiveDate,
+    auctions: usize,
+) -> Option<NaiveDate> {
+    let auctions = i64::try_from(auctions)
+        .unwrap_or(i64::MAX)
+        .saturating_add(1);
+    let weeks = weeks_between_auctions(security_type).saturating_mul(auctions);
+
+    date.checked_sub_signed(Duration::try_weeks(weeks)?)
+}
+
+/// Return the longest time in weeks between two auctions of a tenor of the security type.
+fn weeks_between_auctions(security_type: &SecurityType) -> i64 {
+    match security_type {
+        SecurityType::Bill => WEEKS_BETWEEN_BILLS,
+        SecurityType::Note | SecurityType::Bond | SecurityType::Frn => WEEKS_BETWEEN_COUPONS,
+        _ => WEEKS_BETWEEN_AUCTIONS,
+    }
+}
+
+/// Return the auction followed by the auctions of its security type and tenor
+/// that were held before it, the latest first.
+fn predecessors(auction: &Treasury, history: &[Treasury]) -> AuctionResult<Vec<Treasury>> {
+    let tenor = Tenor::parse(auction.get_term())?;
+    let before =
+        |treasury: &Treasury| match (treasury.get_auction_date(), auction.get_auction_date()) {
+            (Some(date), Some(auction_date)) => date < auction_date,
+            _ => false,
+        };
+
+    let mut predecessors = history
+        .iter()
+        .filter(|treasury| treasury.get_security_type() == auction.get_security_type())
+        .filter(|treasury| tenor.matches(treasury) && before(treasury))
+        .cloned()
+        .collect::<Vec<Treasury>>();
+
+    predecessors.sort_by_key(|treasury| std::cmp::Reverse(treasury.get_auction_date()));
+    predecessors.insert(0, auction.to_owned());
+
+    Ok(predecessors)
+}
+
+/// Sort the qualities by their score, the best first. Qualities without a
+/// score follow, ordered by the legacy quality.
+pub fn sort_by_score(qualities: &mut [AuctionQuality]) {
+    qualities.sort_by(|a, b| {
+        let key = |quality: &AuctionQuality| {
+            (
+                quality.get_score().is_some(),
+                quality.get_score(),
+                quality.get(),
+            )
+        };
+        key(b)
+            .partial_cmp(&key(a))
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::Matcher;
+
+    use crate::{
+        get::TREASURIES_URL,
+        tests::fixture::{api_many_items, api_multiple_items},
+        treasury::Treasuries,
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn it_should_score_every_auction_in_one_pass() {
+        let mut server = mockito::Server::new_async().await;
+        let client = TreasuryClient::builder()
+            .base_url(server.url())
+            .build()
+            .unwrap();
+        let history: Treasuries = serde_json::from_str(api_many_items()).unwrap();
+
+        // The latest 30-year and 20-year bonds.
+        let auctions = &history[..2];
+
+        // The bonds are searched once, 6 months before the earliest auction.
+        let search = server
+            .mock("GET", TREASURIES_URL)
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("type".into(), "Bond".into()),
+                Matcher::UrlEncoded("startDate".into(), "2023-06-21".into()),
+                Matcher::UrlEncoded("endDate".into(), "2024-02-08".into()),
+            ]))
+            .with_body(api_many_items())
+            .expect(1)
+            .create_async()
+            .await;
+
+        let batch = QualityBatch::with_client(&client, Lookback::default());
+        let results = batch.calculate_async(auctions).await.unwrap();
+
+        search.assert_async().await;
+        assert_eq!(2, results.len());
+
+        let mut qualities = results
+            .into_iter()
+            .collect::<AuctionResult<Vec<AuctionQuality>>>()
+            .unwrap();
+        assert_eq!("912810TX6", qualities[0].cusip());
+        assert_eq!(0.743, qualities[0].get());
+
+        sort_by_score(&mut qualities);
+        assert!(qualities[0].get_score() >= qualities[1].get_score());
+    }
+
+    #[tokio::test]
+    async fn it_should_search_the_history_of_every_security_type_once() {
+        let mut server = mockito::Server::new_async().await;
+        let client = TreasuryClient::builder()
+            .base_url(server.url())
+            .build()
+            .unwrap();
+        let auctions: Treasuries = serde_json::from_str(api_multiple_items()).unwrap();
+
+        // Bills, notes, a FRN and a CMB of 2023-12-26 to 2023-12-28. The
+        // weekly bills look back less than the monthly notes.
+        let ranges = [
+            ("Bill", "2023-07-11", "2023-12-28"),
+            ("Note", "2023-05-30", "2023-12-28"),
+            ("FRN", "2023-05-31", "2023-12-27"),
+            ("CMB", "2020-12-30", "2023-12-27"),
+        ];
+
+        let mut searches = Vec::new();
+        for (security_type, since, until) in ranges {
+            let history = auctions
+                .iter()
+                .filter(|a| a.get_security_type().to_string() == security_type)
+                .cloned()
+                .collect::<Treasuries>();
+
+            searches.push(
+                server
+                    .mock("GET", TREASURIES_URL)
+                    .match_query(Matcher::AllOf(vec![
+                        Matcher::UrlEncoded("type".into(), security_type.into()),
+                        Matcher::UrlEncoded("startDate".into(), since.into()),
+                        Matcher::UrlEncoded("endDate".into(), until.into()),
+                    ]))
+                    .with_body(serde_json::to_string(&history).unwrap())
+                    .expect(1)
+                    .create_async()
+                    .await,
+            );
+        }
+
+        let batch = QualityBatch::with_client(&client, Lookback::default());
+        let results = batch.calculate_async(&auctions).await.unwrap();
+
+        for search in searches {
+            search.assert_async().await;
+        }
+        assert_eq!(auctions.len(), results.len());
+    }
+
+    #[test]
+    fn it_should_search_the_whole_history_of_an_endless_lookback() {
+        let date = NaiveDate::from_ymd_opt(2024, 2, 8).unwrap();
+
+        assert_eq!(
+            NaiveDate::from_ymd_opt(2023, 11, 30),
+            since_auctions(&SecurityType::Note, date, 1)
+        );
+        assert_eq!(None, since_auctions(&SecurityType::Bond, date, usize::MAX));
+    }
+}
@@ -0,0 +1,116 @@
+//! The contributions to the quality
+//!
+//! The quality is the sum of the weighted deltas of its components, so every
+//! component tells how much it moved the quality.
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+use serde::{Deserialize, Serialize};
+
+/// A component of the quality.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Component {
+    BidToCover,
+    Dealers,
+    Directs,
+    Indirects,
+    Tail,
+    DiscountMargin,
+}
+
+impl Component {
+    /// Return the name of the component like the output.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Component::BidToCover => "Bid To Cover",
+            Component::Dealers => "Dealers %",
+            Component::Directs => "Directs %",
+            Component::Indirects => "Indirects %",
+            Component::Tail => "Tail (bp)",
+            Component::DiscountMargin => "Discount Margin (bp)",
+        }
+    }
+}
+
+/// The delta of a component to its reference, the mean of the lookback
+/// auctions or a tail of zero, and its weighted contribution to the quality.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Contribution {
+    component: Component,
+    value: Option<Decimal>,
+    reference: Option<Decimal>,
+    weight: f64,
+}
+
+impl Contribution {
+    /// Create the contribution of a component with the given weight.
+    pub fn new(
+        component: Component,
+        value: Option<Decimal>,
+        reference: Option<Decimal>,
+        weight: f64,
+    ) -> Self {
+        Self {
+            component,
+            value,
+            reference,
+            weight,
+        }
+    }
+
+    /// Return the component.
+    pub fn component(&self) -> Component {
+        self.component
+    }
+
+    /// Return the value of the auction.
+    pub fn value(&self) -> Option<Decimal> {
+        self.value
+    }
+
+    /// Return the reference the value is compared to.
+    pub fn reference(&self) -> Option<Decimal> {
+        self.reference
+    }
+
+    /// Return the weight of the profile.
+    pub fn weight(&self) -> f64 {
+        self.weight
+    }
+
+    /// Return the difference of the value to its reference, [`None`] if one is missing.
+    pub fn delta(&self) -> Option<Decimal> {
+        self.value
+            .zip(self.reference)
+            .and_then(|(value, reference)| value.checked_sub(reference))
+    }
+
+    /// Return how much the component moved the quality. A missing delta does
+    /// not move it.
+    pub fn contribution(&self) -> f64 {
+        self.delta()
+            .and_then(|delta| delta.to_f64())
+            .unwrap_or_default()
+            * self.weight
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    #[test]
+    fn it_should_weight_the_delta() {
+        let contribution =
+            Contribution::new(Component::Dealers, Some(dec!(30)), Some(dec!(25)), -0.4);
+
+        assert_eq!(Some(dec!(5)), contribution.delta());
+        assert_eq!(-2.0, contribution.contribution());
+        assert_eq!(
+            0.0,
+            Contribution::new(Component::Tail, None, Some(Decimal::ZERO), -0.4).contribution()
+        );
+    }
+}
@@ -0,0 +1,139 @@
+//! The lookback of the quality
+//!
+//! An auction is compared either to a number of auctions before it or to the
+//! auctions of a calendar window before it, i. e. "5" or "1y". A window looks
+//! back the same time for every tenor, however often it is auctioned.
+use std::{fmt, str::FromStr};
+
+use chrono::{Duration, Months, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    tenor::{Period, Tenor},
+    treasury::{AuctionResult, AuctionResultError},
+};
+
+use super::LAST_AUCTIONS;
+
+/// The auctions to look back.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Lookback {
+    /// The number of auctions before the auction.
+    Auctions(usize),
+    /// The auctions of the calendar window before the auction.
+    Window(Tenor),
+}
+
+impl Default for Lookback {
+    fn default() -> Self {
+        Lookback::Auctions(LAST_AUCTIONS)
+    }
+}
+
+/// A number of auctions, zero for the default.
+impl From<usize> for Lookback {
+    fn from(auctions: usize) -> Self {
+        if auctions == 0 {
+            Lookback::default()
+        } else {
+            Lookback::Auctions(auctions)
+        }
+    }
+}
+
+impl FromStr for Lookback {
+    type Err = AuctionResultError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Lookback::parse(s)
+    }
+}
+
+impl fmt::Display for Lookback {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Lookback::Auctions(auctions) => write!(f, "{} auctions", auctions),
+            Lookback::Window(window) => write!(f, "{}", window),
+        }
+    }
+}
+
+impl Lookback {
+    /// Parse a number of auctions, i. e. "5", or a calendar window like a
+    /// tenor, i. e. "1y" or "6m".
+    pub fn parse(s: &str) -> AuctionResult<Self> {
+        let s = s.trim();
+
+        if let Ok(auctions) = s.parse::<usize>() {
+            return Ok(Lookback::from(auctions));
+        }
+
+        match Tenor::parse(s) {
+            Ok(window) if !window.is_empty() => Ok(Lookback::Window(window)),
+            _ => Err(AuctionResultError::ParseLookback(s.to_owned())),
+        }
+    }
+
+    /// Return the number of auctions, [`None`] for a window.
+    pub fn auctions(&self) -> Option<usize> {
+        match self {
+            Lookback::Auctions(auctions) => Some(*auctions),
+            Lookback::Window(_) => None,
+        }
+    }
+
+    /// Return the first day of the window before the date, [`None`] for a
+    /// number of auctions or a window reaching before the first representable
+    /// date. Years and months are calendar years and months.
+    pub fn since(&self, date: NaiveDate) -> Option<NaiveDate> {
+        let Lookback::Window(window) = self else {
+            return None;
+        };
+
+        window
+            .parts()
+            .iter()
+            .try_fold(date, |date, (security, period)| match period {
+                Period::Year => date.checked_sub_months(Months::new(security.checked_mul(12)?)),
+                Period::Month => date.checked_sub_months(Months::new(*security)),
+                Period::Week => date.checked_sub_signed(Duration::try_weeks(*security as i64)?),
+                Period::Day => date.checked_sub_signed(Duration::try_days(*security as i64)?),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_parse_a_count_or_a_window() {
+        let date = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+
+        assert_eq!(Lookback::Auctions(8), Lookback::parse("8").unwrap());
+        assert_eq!(Lookback::default(), Lookback::parse("0").unwrap());
+        assert_eq!(None, Lookback::parse("8").unwrap().since(date));
+
+        assert_eq!(
+            NaiveDate::from_ymd_opt(2023, 2, 28),
+            Lookback::parse("1y").unwrap().since(date)
+        );
+        assert_eq!(
+            NaiveDate::from_ymd_opt(2023, 8, 15),
+            Lookback::parse("6m 2w").unwrap().since(date)
+        );
+        assert!(matches!(
+            Lookback::parse("a year"),
+            Err(AuctionResultError::ParseLookback(_))
+        ));
+    }
+
+    #[test]
+    fn it_should_not_reach_before_the_first_date() {
+        let date = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+
+        assert_eq!(None, Lookback::parse("200000000y").unwrap().since(date));
+        assert_eq!(None, Lookback::parse("400000000w").unwrap().since(date));
+    }
+}
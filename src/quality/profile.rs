@@ -0,0 +1,264 @@
+//! The scoring profiles
+//!
+//! A profile weights the components of the quality. The weights are signed:
+//! a positive weight raises the quality if the component is above its mean.
+//! Profiles are loaded from a toml file with a table per profile, missing
+//! weights are taken from the default profile:
+//!
+//! ```toml
+//! [profiles.auction-tails]
+//! bid_to_cover = 0.25
+//! when_issued = -0.8
+//! ```
+use std::{collections::BTreeMap, fs, io::ErrorKind, path::Path, path::PathBuf};
+
+use directories::ProjectDirs;
+use serde::{Deserialize, Serialize};
+
+use crate::treasury::{AuctionResult, AuctionResultError};
+
+use super::{
+    BID_TO_COVER_RATIO_WEIGHT, DIRECT_BIDDER_WEIGHT, DISCOUNT_MARGIN_WEIGHT,
+    INDIRECT_BIDDER_WEIGHT, PRIMARY_DEALER_ACCEPTED_WEIGHT, WHEN_ISSUED_WEIGHT,
+};
+
+/// The name of the default profile.
+pub const DEFAULT_PROFILE: &str = "default";
+
+/// The name of the file of the profiles in the config directory.
+const PROFILES_FILE: &str = "profiles.toml";
+
+/// The weights of the components of the quality.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Profile {
+    #[serde(skip)]
+    name: String,
+    bid_to_cover: f64,
+    primary_dealers: f64,
+    indirect_bidders: f64,
+    direct_bidders: f64,
+    when_issued: f64,
+    discount_margin: f64,
+}
+
+impl Default for Profile {
+    /// The weights the quality was always calculated with.
+    fn default() -> Self {
+        Self {
+            name: DEFAULT_PROFILE.to_owned(),
+            bid_to_cover: BID_TO_COVER_RATIO_WEIGHT,
+            primary_dealers: -PRIMARY_DEALER_ACCEPTED_WEIGHT,
+            indirect_bidders: -INDIRECT_BIDDER_WEIGHT,
+            direct_bidders: -DIRECT_BIDDER_WEIGHT,
+            when_issued: -WHEN_ISSUED_WEIGHT,
+            discount_margin: -DISCOUNT_MARGIN_WEIGHT,
+        }
+    }
+}
+
+impl Profile {
+    /// Create a profile with the given name and weights. The discount margin
+    /// is weighted like the default profile, see [`Profile::with_discount_margin`].
+    pub fn new(
+        name: impl Into<String>,
+        bid_to_cover: f64,
+        primary_dealers: f64,
+        indirect_bidders: f64,
+        direct_bidders: f64,
+        when_issued: f64,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            bid_to_cover,
+            primary_dealers,
+            indirect_bidders,
+            direct_bidders,
+            when_issued,
+            discount_margin: -DISCOUNT_MARGIN_WEIGHT,
+        }
+    }
+
+    /// Weight the discount margin of a FRN by the given weight.
+    pub fn with_discount_margin(mut self, discount_margin: f64) -> Self {
+        self.discount_margin = discount_margin;
+        self
+    }
+
+    /// Weight the demand of the indirect bidders, the foreign demand, and the
+    /// direct bidders instead of penalizing them.
+    pub fn foreign_demand() -> Self {
+        Self::new("foreign-demand", 0.3, -0.2, 0.4, 0.1, -0.4)
+    }
+
+    /// Weight the amount left to the primary dealers and the tail heavily.
+    pub fn dealer_stress() -> Self {
+        Self::new("dealer-stress", 0.3, -0.8, 0.05, 0.05, -0.6).with_discount_margin(-0.6)
+    }
+
+    /// Return the name of the profile.
+    pub fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    /// Return the weight of the bid to cover ratio.
+    pub fn bid_to_cover(&self) -> f64 {
+        self.bid_to_cover
+    }
+
+    /// Return the weight of the percentage accepted by primary dealers.
+    pub fn primary_dealers(&self) -> f64 {
+        self.primary_dealers
+    }
+
+    /// Return the weight of the percentage accepted by indirect bidders.
+    pub fn indirect_bidders(&self) -> f64 {
+        self.indirect_bidders
+    }
+
+    /// Return the weight of the percentage accepted by direct bidders.
+    pub fn direct_bidders(&self) -> f64 {
+        self.direct_bidders
+    }
+
+    /// Return the weight of the tail in basis points.
+    pub fn when_issued(&self) -> f64 {
+        self.when_issued
+    }
+
+    /// Return the weight of the discount margin of a FRN in basis points.
+    pub fn discount_margin(&self) -> f64 {
+        self.discount_margin
+    }
+}
+
+/// The built-in profiles and the profiles of a config file by their names.
+#[derive(Debug, Clone)]
+pub struct Profiles {
+    profiles: BTreeMap<String, Profile>,
+}
+
+impl Default for Profiles {
+    /// Return the built-in profiles.
+    fn default() -> Self {
+        let profiles = [
+            Profile::default(),
+            Profile::foreign_demand(),
+            Profile::dealer_stress(),
+        ];
+
+        Self {
+            profiles: profiles
+                .into_iter()
+                .map(|profile| (profile.name.to_owned(), profile))
+                .collect(),
+        }
+    }
+}
+
+/// The layout of the config file.
+#[derive(Deserialize)]
+struct Config {
+    #[serde(default)]
+    profiles: BTreeMap<String, Profile>,
+}
+
+impl Profiles {
+    /// Parse the profiles of a toml config in addition to the built-in
+    /// profiles, which can be overridden.
+    pub fn parse(toml: &str) -> AuctionResult<Self> {
+        let config: Config = toml::from_str(toml).map_err(AuctionResultError::ParseProfile)?;
+        let mut profiles = Self::default();
+
+        for (name, mut profile) in config.profiles {
+            profile.name = name.to_owned();
+            profiles.profiles.insert(name, profile);
+        }
+
+        Ok(profiles)
+    }
+
+    /// Load the profiles of the toml file, the built-in profiles if there is none.
+    pub fn load(path: impl AsRef<Path>) -> AuctionResult<Self> {
+        match fs::read_to_string(path) {
+            Ok(toml) => Self::parse(&toml),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(AuctionResultError::ReadProfile(e)),
+        }
+    }
+
+    /// Return the path of the profiles in the config directory of the platform.
+    pub fn default_path() -> Option<PathBuf> {
+        ProjectDirs::from("", "", "auctionresult")
+            .map(|dirs| dirs.config_dir().join(PROFILES_FILE))
+    }
+
+    /// Return the profile with the given name.
+    pub fn get(&self, name: &str) -> AuctionResult<Profile> {
+        self.profiles
+            .get(name)
+            .cloned()
+            .ok_or_else(|| AuctionResultError::UnknownProfile(name.to_owned()))
+    }
+
+    /// Return the names of the profiles.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.profiles.keys().map(String::as_str)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_load_profiles_from_toml() {
+        let toml = r#"
+            [profiles.auction-tails]
+            bid_to_cover = 0.25
+            when_issued = -0.8
+            discount_margin = -0.2
+
+            [profiles.default]
+            bid_to_cover = 1.0
+        "#;
+
+        let profiles = Profiles::parse(toml).unwrap();
+        let tails = profiles.get("auction-tails").unwrap();
+
+        assert_eq!("auction-tails", tails.name());
+        assert_eq!(0.25, tails.bid_to_cover());
+        assert_eq!(-0.8, tails.when_issued());
+        assert_eq!(-0.2, tails.discount_margin());
+        assert_eq!(-PRIMARY_DEALER_ACCEPTED_WEIGHT, tails.primary_dealers());
+        assert_eq!(1.0, profiles.get(DEFAULT_PROFILE).unwrap().bid_to_cover());
+        assert!(profiles.get("foreign-demand").is_ok());
+    }
+
+    #[test]
+    fn it_should_reject_unknown_profiles_and_weights() {
+        assert!(matches!(
+            Profiles::default().get("hawkish"),
+            Err(AuctionResultError::UnknownProfile(_))
+        ));
+        assert!(matches!(
+            Profiles::parse("[profiles.typo]\nbid_to_covr = 0.5"),
+            Err(AuctionResultError::ParseProfile(_))
+        ));
+    }
+
+    #[test]
+    fn it_should_fall_back_only_without_a_profiles_file() {
+        let dir = std::env::temp_dir().join("auctionresult-profiles");
+        fs::create_dir_all(&dir).unwrap();
+
+        let profiles = Profiles::load(dir.join("missing.toml")).unwrap();
+        assert!(profiles.get(DEFAULT_PROFILE).is_ok());
+
+        // A directory cannot be read as a file.
+        assert!(matches!(
+            Profiles::load(&dir),
+            Err(AuctionResultError::ReadProfile(_))
+        ));
+    }
+}
@@ -0,0 +1,114 @@
+//! The statistics of a component of the quality
+//!
+//! A component of an auction is normalized against the lookback auctions, so
+//! that auctions of different tenors can be compared.
+use serde::{Deserialize, Serialize};
+
+/// The z-score and the percentile rank of a component against the lookback auctions.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Statistics {
+    z_score: Option<f64>,
+    percentile: Option<f64>,
+}
+
+impl Statistics {
+    /// Calculate the statistics of the value against the values of the lookback
+    /// auctions. Without a value or with less than two lookback values there
+    /// are no statistics, without a deviation there is no z-score.
+    pub fn new(value: Option<f64>, lookback: &[f64]) -> Self {
+        let Some(value) = value.filter(|_| lookback.len() > 1) else {
+            return Self::default();
+        };
+
+        let n = lookback.len() as f64;
+        let mean = lookback.iter().sum::<f64>() / n;
+        let deviation =
+            (lookback.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (n - 1.0)).sqrt();
+
+        // Ties count half, so that the value of an unchanged history ranks at 50.
+        let below = lookback.iter().filter(|v| **v < value).count() as f64;
+        let equal = lookback.iter().filter(|v| **v == value).count() as f64;
+
+        Self {
+            z_score: (deviation > 0.0).then(|| (value - mean) / deviation),
+            percentile: Some((below + equal / 2.0) / n * 100.0),
+        }
+    }
+
+    /// Return the number of standard deviations the value is off the mean.
+    pub fn z_score(&self) -> Option<f64> {
+        self.z_score
+    }
+
+    /// Return the percentage of the lookback auctions below the value.
+    pub fn percentile(&self) -> Option<f64> {
+        self.percentile
+    }
+}
+
+/// Return the score from 0 to 100 of the weighted z-scores. The weighted sum
+/// is scaled to a standard deviation of one and mapped through the standard
+/// normal distribution, so an average auction scores 50.
+pub fn score(weighted: &[(f64, Option<f64>)]) -> Option<f64> {
+    let components = weighted
+        .iter()
+        .filter_map(|(weight, z_score)| z_score.map(|z| (*weight, z)))
+        .filter(|(weight, _)| *weight != 0.0)
+        .collect::<Vec<(f64, f64)>>();
+
+    if components.is_empty() {
+        return None;
+    }
+
+    let sum = components.iter().map(|(weight, z)| weight * z).sum::<f64>();
+    let scale = components
+        .iter()
+        .map(|(weight, _)| weight.powi(2))
+        .sum::<f64>()
+        .sqrt();
+
+    Some((normal_cdf(sum / scale) * 1000.0).round() / 10.0)
+}
+
+/// Return the standard normal distribution at x, see Abramowitz and Stegun 7.1.26.
+fn normal_cdf(x: f64) -> f64 {
+    let t = 1.0 / (1.0 + 0.3275911 * x.abs() / std::f64::consts::SQRT_2);
+    let polynomial = t
+        * (0.254829592
+            + t * (-0.284496736 + t * (1.421413741 + t * (-1.453152027 + t * 1.061405429))));
+    let erf = 1.0 - polynomial * (-x * x / 2.0).exp();
+
+    if x >= 0.0 {
+        (1.0 + erf) / 2.0
+    } else {
+        (1.0 - erf) / 2.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_normalize_a_value_against_the_lookback() {
+        let statistics = Statistics::new(Some(2.8), &[2.2, 2.4, 2.6, 2.4]);
+
+        assert!((statistics.z_score().unwrap() - 2.449).abs() < 0.001);
+        assert_eq!(Some(100.0), statistics.percentile());
+        assert_eq!(
+            Some(50.0),
+            Statistics::new(Some(2.4), &[2.4, 2.4]).percentile()
+        );
+        assert_eq!(None, Statistics::new(Some(2.4), &[2.4, 2.4]).z_score());
+        assert_eq!(Statistics::default(), Statistics::new(None, &[2.2, 2.4]));
+    }
+
+    #[test]
+    fn it_should_score_from_0_to_100() {
+        assert_eq!(Some(50.0), score(&[(0.5, Some(0.0)), (-0.4, Some(0.0))]));
+        assert_eq!(Some(84.1), score(&[(0.5, Some(1.0)), (-0.4, None)]));
+        assert_eq!(Some(15.9), score(&[(-0.4, Some(1.0))]));
+        assert_eq!(None, score(&[(0.5, None)]));
+    }
+}
@@ -0,0 +1,204 @@
+//! # The Search Module
+//!
+//! searches the auctions of a security type and a tenor within a date range.
+use chrono::NaiveDate;
+
+#[cfg(feature = "blocking")]
+use crate::treasury::{load::load, BlockingTreasuryAccess};
+use crate::{
+    client::TreasuryClient,
+    get::TREASURIES_URL,
+    tenor::Tenor,
+    treasury::{
+        load::{decode, load_async}, pager::Pager, AsyncTreasuryAccess, AuctionResult, SecurityType,
+        Treasuries, Treasury, TreasuryAccess,
+    },
+};
+
+/// The format of the dates in the query of the api.
+const QUERY_DATE_FORMAT: &str = "%Y-%m-%d";
+
+/// Descriptor of the Search module.
+#[derive(Debug)]
+pub struct Search<'c> {
+    client: &'c TreasuryClient,
+    security_type: SecurityType,
+    tenor: Tenor,
+    since: Option<NaiveDate>,
+    until: Option<NaiveDate>,
+    limit: Option<usize>,
+}
+
+impl TreasuryAccess<Treasuries> for Search<'_> {
+    fn url(&self) -> String {
+        let mut url = format!("{}{}?format=json", self.client.base_url(), TREASURIES_URL);
+
+        if self.security_type != SecurityType::Null {
+            url.push_str("&type=");
+            url.push_str(&self.security_type.to_string());
+        }
+
+        if self.since.is_some() || self.until.is_some() {
+            url.push_str("&dateFieldName=auctionDate");
+        }
+
+        if let Some(since) = self.since {
+            url.push_str("&startDate=");
+            url.push_str(&since.format(QUERY_DATE_FORMAT).to_string());
+        }
+
+        if let Some(until) = self.until {
+            url.push_str("&endDate=");
+            url.push_str(&until.format(QUERY_DATE_FORMAT).to_string());
+        }
+
+        url
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl BlockingTreasuryAccess<Treasuries> for Search<'_> {
+    /// Search the auctions, following the pages of the api up to the limit or the date bound.
+    fn get(&self) -> AuctionResult<Treasuries> {
+        let url = self.url();
+        let mut pager = self.pager();
+
+        while let Some(page_url) = pager.next_url(&url) {
+            let body = load(self.client, page_url)?;
+            pager.push(decode(&body)?, |t| self.keep(t));
+        }
+
+        Ok(pager.finish())
+    }
+}
+
+impl AsyncTreasuryAccess<Treasuries> for Search<'_> {
+    /// Search the auctions without blocking the current thread.
+    async fn get_async(&self) -> AuctionResult<Treasuries> {
+        let url = self.url();
+        let mut pager = self.pager();
+
+        while let Some(page_url) = pager.next_url(&url) {
+            let body = load_async(self.client, page_url).await?;
+            pager.push(decode(&body)?, |t| self.keep(t));
+        }
+
+        Ok(pager.finish())
+    }
+}
+
+impl Search<'static> {
+    /// Create a new Search module for the given security type and tenor, using
+    /// the shared client. Without a limit or a date bound only [`250`] records
+    /// are returned.
+    pub fn new(security_type: SecurityType, tenor: Tenor) -> Self {
+        Self::with_client(TreasuryClient::shared(), security_type, tenor)
+    }
+}
+
+impl<'c> Search<'c> {
+    /// Create a new Search module like [`Search::new`], using the given client.
+    pub fn with_client(
+        client: &'c TreasuryClient,
+        security_type: SecurityType,
+        tenor: Tenor,
+    ) -> Self {
+        Self {
+            client,
+            security_type,
+            tenor,
+            since: None,
+            until: None,
+            limit: None,
+        }
+    }
+
+    /// Return only the auctions held on or after the given date.
+    pub fn set_since(&mut self, since: NaiveDate) {
+        self.since = Some(since);
+    }
+
+    /// Return only the auctions held on or before the given date.
+    pub fn set_until(&mut self, until: NaiveDate) {
+        self.until = Some(until);
+    }
+
+    /// Return at most the given number of auctions.
+    pub fn set_limit(&mut self, limit: usize) {
+        self.limit = Some(limit);
+    }
+
+    /// Create the pager for the limit and the date bound.
+    fn pager(&self) -> Pager {
+        Pager::new(self.limit, self.since)
+    }
+
+    /// Keep the treasuries of the security type with the tenor, that were not
+    /// auctioned after the date bound.
+    fn keep(&self, treasury: &Treasury) -> bool {
+        let in_range = match (self.until, treasury.get_auction_date()) {
+            (Some(until), Some(auction_date)) => auction_date.date() <= until,
+            _ => true,
+        };
+
+        in_range && self.security_type.matches(treasury) && self.tenor.matches(treasury)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::Matcher;
+
+    use super::*;
+    use crate::tests::fixture::api_many_items;
+
+    #[test]
+    fn it_should_build_the_search_url() {
+        let client = TreasuryClient::builder()
+            .base_url("http://localhost")
+            .build()
+            .unwrap();
+        let mut search = Search::with_client(&client, SecurityType::Bond, Tenor::default());
+        search.set_since(NaiveDate::from_ymd_opt(2019, 1, 1).unwrap());
+        search.set_until(NaiveDate::from_ymd_opt(2020, 12, 31).unwrap());
+
+        assert_eq!(
+            "http://localhost/securities/search?format=json&type=Bond&dateFieldName=auctionDate&startDate=2019-01-01&endDate=2020-12-31",
+            search.url()
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_search_the_auctions_within_the_date_range() {
+        let mut server = mockito::Server::new_async().await;
+        let client = TreasuryClient::builder()
+            .base_url(server.url())
+            .build()
+            .unwrap();
+        let since = NaiveDate::from_ymd_opt(2019, 1, 1).unwrap();
+        let until = NaiveDate::from_ymd_opt(2020, 12, 31).unwrap();
+        let tenor = Tenor::parse("30y").unwrap();
+        let mut search =
+            Search::with_client(&client, SecurityType::Bond, Tenor::parse("30y").unwrap());
+        search.set_since(since);
+        search.set_until(until);
+
+        server
+            .mock("GET", TREASURIES_URL)
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("type".into(), "Bond".into()),
+                Matcher::UrlEncoded("pagenum".into(), "0".into()),
+            ]))
+            .with_body(api_many_items())
+            .create_async()
+            .await;
+
+        let response = search.get_async().await.unwrap();
+
+        assert!(!response.is_empty());
+        assert!(response.iter().all(|t| {
+            let auction_date = t.get_auction_date().unwrap().date();
+            tenor.matches(t) && auction_date >= since && auction_date <= until
+        }));
+    }
+}
@@ -1,6 +1,30 @@
 //! # Parser for tenors.
 //!
-const RE: &str = r"^(?P<security>\d+)([-])?(?P<term>\w+)$";
+//! A tenor is one or more periods, like the terms of the api: "10-Year",
+//! "52-Week" or "19-Year 11-Month". Tenors are compared by their nominal
+//! duration, so 52-Week and 1-Year are equal.
+const RE: &str = r"(?P<security>\d+)\s*-?\s*(?P<term>[[:alpha:]]+)";
+
+/// The separator of the bounds of a tenor range, i. e. 2y..10y.
+const RANGE_SEPARATOR: &str = "..";
+
+/// The nominal benchmarks of bills, notes and bonds, ordered by duration.
+const BENCHMARKS: [(u32, Period); 14] = [
+    (4, Period::Week),
+    (6, Period::Week),
+    (8, Period::Week),
+    (13, Period::Week),
+    (17, Period::Week),
+    (26, Period::Week),
+    (52, Period::Week),
+    (2, Period::Year),
+    (3, Period::Year),
+    (5, Period::Year),
+    (7, Period::Year),
+    (10, Period::Year),
+    (20, Period::Year),
+    (30, Period::Year),
+];
 
 macro_rules! re {
     ($re:ident $(,)?) => {{
@@ -9,78 +33,315 @@ macro_rules! re {
     }};
 }
 
-use crate::treasury::{AuctionResult, AuctionResultError};
-#[derive(Debug, PartialEq, Default)]
+use std::{cmp::Ordering, hash::Hash};
+
+use chrono::Duration;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::treasury::{AuctionResult, AuctionResultError, Treasury};
+
+/// The period of a tenor. A nominal year has 52 weeks, i. e. 364 days, and a
+/// month is a twelfth of it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Period {
+    Day,
+    Week,
+    Month,
+    Year,
+}
+
+impl Period {
+    /// Return the period of the first letter, i. e. "y", "m", "w" or "d".
+    fn from_shortcut(shortcut: char) -> Option<Self> {
+        match shortcut.to_ascii_lowercase() {
+            'd' => Some(Period::Day),
+            'w' => Some(Period::Week),
+            'm' => Some(Period::Month),
+            'y' => Some(Period::Year),
+            _ => None,
+        }
+    }
+
+    /// Return the name of the period like the api.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Period::Day => "Day",
+            Period::Week => "Week",
+            Period::Month => "Month",
+            Period::Year => "Year",
+        }
+    }
+
+    /// Return the shortcut of the period.
+    pub fn shortcut(&self) -> &'static str {
+        match self {
+            Period::Day => "d",
+            Period::Week => "w",
+            Period::Month => "m",
+            Period::Year => "y",
+        }
+    }
+
+    /// Return the nominal duration of the period.
+    pub fn duration(&self) -> Duration {
+        match self {
+            Period::Day => Duration::days(1),
+            Period::Week => Duration::weeks(1),
+            Period::Month => Duration::weeks(52) / 12,
+            Period::Year => Duration::weeks(52),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
 pub struct Tenor {
-    security: u32,
-    term: String,
-    shortcut: String,
+    parts: Vec<(u32, Period)>,
 }
 
 impl std::fmt::Display for Tenor {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}-{}", self.security, self.term)
+        for (i, (security, period)) in self.parts.iter().enumerate() {
+            if i > 0 {
+                f.write_str(" ")?;
+            }
+            write!(f, "{}-{}", security, period.name())?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Serialize the tenor like the api, i. e. "10-Year".
+impl Serialize for Tenor {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Tenor {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+
+        Tenor::parse(s.as_str()).map_err(|_| de::Error::invalid_value(de::Unexpected::Str(&s), &"a tenor"))
+    }
+}
+
+/// Tenors are equal if their durations are equal.
+impl PartialEq for Tenor {
+    fn eq(&self, other: &Self) -> bool {
+        self.duration() == other.duration()
+    }
+}
+
+impl Eq for Tenor {}
+
+impl Hash for Tenor {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.duration().hash(state);
+    }
+}
+
+/// Tenors are ordered by their durations.
+impl PartialOrd for Tenor {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Tenor {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.duration().cmp(&other.duration())
     }
 }
 
 impl Tenor {
-    /// Contruct [`Tenor`] by parsing.
+    /// Contruct [`Tenor`] by parsing. The periods can be given like the api,
+    /// i. e. "19-Year 11-Month", or short, i. e. "10y" or "19y11m".
     pub fn parse(s: impl Into<String>) -> AuctionResult<Self> {
         let s = s.into();
+        let mut parts = Vec::new();
+        let mut end = 0;
 
-        if s.is_empty() {
-            return Ok(Tenor::default());
-        }
-        // Guard
-        let Some(captures) = re!(RE).captures(&s) else {
-            return Err(AuctionResultError::ParseTenor);
-        };
+        for captures in re!(RE).captures_iter(&s) {
+            let whole = captures.get(0).unwrap();
 
-        let security: &str = captures.name("security").map_or("", |m| m.as_str());
-        let period: &str = captures.name("term").map_or("", |m| m.as_str());
+            // Guard: only whitespace between the periods.
+            if !s[end..whole.start()].trim().is_empty() {
+                return Err(AuctionResultError::ParseTenor);
+            }
+            end = whole.end();
 
-        if security.is_empty() || period.is_empty() {
+            let Ok(security) = captures["security"].parse::<u32>() else {
+                return Err(AuctionResultError::ParseTenor);
+            };
+            let Some(period) = captures["term"].chars().next().and_then(Period::from_shortcut)
+            else {
+                return Err(AuctionResultError::ParseTenor);
+            };
+
+            parts.push((security, period));
+        }
+
+        if !s[end..].trim().is_empty() {
             return Err(AuctionResultError::ParseTenor);
         }
-        let shortcut = period.to_lowercase().chars().next().unwrap_or('x');
-        let term = match shortcut {
-            'y' => "Year",
-            'w' => "Weeks",
-            'm' => "Months",
-            'd' => "Day",
-            _ => return Err(AuctionResultError::ParseTenor),
-        };
 
-        // Guard
-        let Ok(sec) = security.parse::<u32>() else {
+        // Guard: the duration must not overflow, i. e. "999999999y".
+        let tenor = Self { parts };
+        if tenor.checked_duration().is_none() {
             return Err(AuctionResultError::ParseTenor);
-        };
+        }
 
-        Ok(Self {
-            term: term.to_owned(),
-            security: sec,
-            shortcut: shortcut.to_string(),
-        })
+        Ok(tenor)
     }
 
     /// Detect if the tenor is empty.
     pub fn is_empty(&self) -> bool {
-        self.term.is_empty() && self.security == 0
+        self.parts.is_empty()
     }
-    
-    /// Return the security.
+
+    /// Return the security, i. e. the number of the first period.
     pub fn security(&self) -> u32 {
-        self.security
-    } 
+        self.parts.first().map_or(0, |(security, _)| *security)
+    }
 
-    /// Return the shortcut. Can be: "y", "m", "w" or "d".
+    /// Return the shortcut of the first period. Can be: "y", "m", "w" or "d".
     pub fn shortcut(&self) -> &str {
-        self.shortcut.as_str()
+        self.parts.first().map_or("", |(_, period)| period.shortcut())
     }
 
-    /// Return the term.
+    /// Return the term of the first period.
     pub fn term(&self) -> &str {
-        self.term.as_str()
+        self.parts.first().map_or("", |(_, period)| period.name())
+    }
+
+    /// Return the periods of the tenor.
+    pub fn parts(&self) -> &[(u32, Period)] {
+        &self.parts
+    }
+
+    /// Return the nominal duration of the tenor, see [`Period::duration`].
+    pub fn duration(&self) -> Duration {
+        // A parsed tenor is checked to have a duration.
+        self.checked_duration().unwrap_or(Duration::MAX)
+    }
+
+    /// Return the nominal duration of the tenor or None if it overflows.
+    fn checked_duration(&self) -> Option<Duration> {
+        self.parts
+            .iter()
+            .try_fold(Duration::zero(), |sum, (security, period)| {
+                let security = i32::try_from(*security).ok()?;
+                sum.checked_add(&period.duration().checked_mul(security)?)
+            })
+    }
+
+    /// Return if the treasury has this tenor. An empty tenor matches every treasury.
+    pub fn matches(&self, treasury: &Treasury) -> bool {
+        self.is_empty() || terms(treasury).any(|tenor| tenor == *self)
+    }
+
+    /// Return the benchmark bucket of the tenor, the shortest benchmark that is
+    /// not shorter, i. e. 10-Year for 9-Year 10-Month. Tenors beyond the
+    /// longest benchmark fall under it.
+    pub fn bucket(&self) -> Option<Tenor> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let mut benchmarks = BENCHMARKS.iter().map(|part| Tenor { parts: vec![*part] });
+        benchmarks.clone().find(|benchmark| benchmark >= self).or_else(|| benchmarks.next_back())
+    }
+
+    /// Return the effective tenor of the treasury, the remaining term of the
+    /// security term, or the term and the original security term if it is missing.
+    pub fn effective(treasury: &Treasury) -> Option<Tenor> {
+        [
+            treasury.get_security_term(),
+            treasury.get_term(),
+            treasury.get_original_security_term(),
+        ]
+        .into_iter()
+        .filter_map(|term| Tenor::parse(term).ok())
+        .find(|tenor| !tenor.is_empty())
+    }
+
+    /// Return the benchmark bucket of the effective tenor of the treasury.
+    pub fn bucket_of(treasury: &Treasury) -> Option<Tenor> {
+        Tenor::effective(treasury).and_then(|tenor| tenor.bucket())
+    }
+
+    /// Return if the treasury falls under the bucket of this tenor. An empty
+    /// tenor matches every treasury.
+    pub fn matches_bucket(&self, treasury: &Treasury) -> bool {
+        self.is_empty() || Tenor::bucket_of(treasury) == self.bucket()
+    }
+}
+
+/// Return the tenors of the term, the security term and the original security
+/// term of the treasury, that can be parsed.
+fn terms(treasury: &Treasury) -> impl Iterator<Item = Tenor> + '_ {
+    [
+        treasury.get_term(),
+        treasury.get_security_term(),
+        treasury.get_original_security_term(),
+    ]
+    .into_iter()
+    .filter_map(|term| Tenor::parse(term).ok())
+    .filter(|tenor| !tenor.is_empty())
+}
+
+/// A range of tenors, i. e. 2y..10y. Both bounds are inclusive, an empty bound
+/// is open.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TenorRange {
+    from: Tenor,
+    to: Tenor,
+}
+
+impl TenorRange {
+    /// Construct [`TenorRange`] by parsing two tenors separated by "..".
+    pub fn parse(s: impl Into<String>) -> AuctionResult<Self> {
+        let s = s.into();
+
+        let Some((from, to)) = s.split_once(RANGE_SEPARATOR) else {
+            return Err(AuctionResultError::ParseTenor);
+        };
+
+        Ok(Self {
+            from: Tenor::parse(from)?,
+            to: Tenor::parse(to)?,
+        })
+    }
+
+    /// Detect if the string is a range of tenors.
+    pub fn is_range(s: &str) -> bool {
+        s.contains(RANGE_SEPARATOR)
+    }
+
+    /// Return the lower bound.
+    pub fn from(&self) -> &Tenor {
+        &self.from
+    }
+
+    /// Return the upper bound.
+    pub fn to(&self) -> &Tenor {
+        &self.to
+    }
+
+    /// Return if the tenor is within the bounds.
+    pub fn contains(&self, tenor: &Tenor) -> bool {
+        (self.from.is_empty() || *tenor >= self.from) && (self.to.is_empty() || *tenor <= self.to)
+    }
+
+    /// Return if a term of the treasury is within the bounds.
+    pub fn matches(&self, treasury: &Treasury) -> bool {
+        terms(treasury).any(|tenor| self.contains(&tenor))
+    }
+
+    /// Return if the benchmark bucket of the treasury is within the bounds.
+    pub fn matches_bucket(&self, treasury: &Treasury) -> bool {
+        Tenor::bucket_of(treasury).is_some_and(|bucket| self.contains(&bucket))
     }
 }
 
@@ -94,8 +355,8 @@ mod tests {
 
         assert!(result.is_ok());
         let tenor = result.unwrap();
-        assert_eq!(tenor.security, 10);
-        assert_eq!(tenor.term, "Year");
+        assert_eq!(tenor.security(), 10);
+        assert_eq!(tenor.term(), "Year");
         assert_eq!(tenor.to_string(), "10-Year");
     }
 
@@ -104,5 +365,82 @@ mod tests {
         let result = Tenor::parse("--Y");
 
         assert!(matches!(result, Err(AuctionResultError::ParseTenor)));
+        assert!(Tenor::parse("10x").is_err());
+        assert!(Tenor::parse("10y and").is_err());
+    }
+
+    #[test]
+    fn it_should_reject_a_tenor_that_overflows() {
+        assert!(matches!(
+            Tenor::parse("999999999y"),
+            Err(AuctionResultError::ParseTenor)
+        ));
+        assert!(matches!(
+            Tenor::parse("4294967295d"),
+            Err(AuctionResultError::ParseTenor)
+        ));
+        assert!(matches!(
+            Tenor::parse("200000000y 200000000y"),
+            Err(AuctionResultError::ParseTenor)
+        ));
+        assert!(Tenor::parse("100y").is_ok());
+    }
+
+    #[test]
+    fn it_should_serialize_a_tenor_like_the_api() {
+        let tenor = Tenor::parse("10y").unwrap();
+
+        let json = serde_json::to_string(&tenor).unwrap();
+
+        assert_eq!("\"10-Year\"", json);
+        assert_eq!(tenor, serde_json::from_str::<Tenor>(&json).unwrap());
+    }
+
+    #[test]
+    fn it_should_parse_the_terms_of_the_api() {
+        let tenor = Tenor::parse("19-Year 11-Month").unwrap();
+
+        assert_eq!("19-Year 11-Month", tenor.to_string());
+        assert_eq!(Tenor::parse("19y11m").unwrap(), tenor);
+        assert_eq!("13-Week", Tenor::parse("13-Week").unwrap().to_string());
+        assert_eq!("42-Day", Tenor::parse("42-Day").unwrap().to_string());
+    }
+
+    #[test]
+    fn it_should_order_the_tenors_by_duration() {
+        let parse = |s| Tenor::parse(s).unwrap();
+
+        assert_eq!(parse("52-Week"), parse("1-Year"));
+        assert_eq!(parse("12-Month"), parse("1y"));
+        assert!(parse("26-Week") < parse("1y"));
+        assert!(parse("19-Year 11-Month") < parse("20y"));
+        assert!(parse("9-Year 10-Month") > parse("7y"));
+    }
+
+    #[test]
+    fn it_should_parse_a_range_of_tenors() {
+        let range = TenorRange::parse("2y..10y").unwrap();
+        let parse = |s| Tenor::parse(s).unwrap();
+
+        assert!(range.contains(&parse("2-Year")));
+        assert!(range.contains(&parse("9-Year 10-Month")));
+        assert!(range.contains(&parse("10-Year")));
+        assert!(!range.contains(&parse("52-Week")));
+        assert!(!range.contains(&parse("20-Year")));
+        assert!(TenorRange::parse("..1y").unwrap().contains(&parse("4-Week")));
+        assert!(TenorRange::parse("2y").is_err());
+    }
+
+    #[test]
+    fn it_should_bucket_a_tenor_under_its_benchmark() {
+        let bucket = |s| Tenor::parse(s).unwrap().bucket().unwrap().to_string();
+
+        assert_eq!("10-Year", bucket("9-Year 10-Month"));
+        assert_eq!("20-Year", bucket("19-Year 10-Month"));
+        assert_eq!("2-Year", bucket("1-Year 10-Month"));
+        assert_eq!("6-Week", bucket("42-Day"));
+        assert_eq!("52-Week", bucket("1y"));
+        assert_eq!("30-Year", bucket("40y"));
+        assert_eq!(None, Tenor::default().bucket());
     }
 }
@@ -1715,3 +1715,43 @@ pub(crate) fn api_30y_very_old_bond_item() -> &'static str {
 pub(crate) fn api_many_items() -> &'static str {
     include_str!("./bond.json")
 }
+
+/// Return the 30 year bond as a TIPS with its inflation adjustment.
+pub(crate) fn api_30y_tips_item() -> String {
+    let mut items: serde_json::Value = serde_json::from_str(api_30y_bond_item()).unwrap();
+
+    items[0]["tips"] = "Yes".into();
+    items[0]["refCpiOnIssueDate"] = "255.14387".into();
+    items[0]["indexRatioOnIssueDate"] = "1.01234".into();
+
+    items.to_string()
+}
+
+/// Return the 2 year FRN of 2023-12-27 and its reopenings of the three months
+/// before, the latest first. The discount margin rises from 0.20% to 0.25%.
+pub(crate) fn api_frn_items() -> String {
+    let items: Vec<serde_json::Value> = serde_json::from_str(api_multiple_items()).unwrap();
+    let frn = items
+        .into_iter()
+        .find(|item| item["type"] == "FRN")
+        .unwrap();
+
+    let auctions = [
+        ("2023-12-27T00:00:00", "0.250000"),
+        ("2023-11-22T00:00:00", "0.240000"),
+        ("2023-10-25T00:00:00", "0.220000"),
+        ("2023-09-27T00:00:00", "0.200000"),
+    ];
+
+    let items = auctions
+        .iter()
+        .map(|(auction_date, margin)| {
+            let mut item = frn.clone();
+            item["auctionDate"] = (*auction_date).into();
+            item["highDiscountMargin"] = (*margin).into();
+            item
+        })
+        .collect::<Vec<serde_json::Value>>();
+
+    serde_json::Value::from(items).to_string()
+}
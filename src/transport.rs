@@ -0,0 +1,355 @@
+//! # The transports used to load the responses of the api.
+//!
+//! The library loads every response through the [`Transport`] of the
+//! [`TreasuryClient`](crate::TreasuryClient). Besides the [`HttpTransport`],
+//! which talks to the api, there is a [`RecordingTransport`], which saves the
+//! responses of another transport to a fixtures directory, and a
+//! [`ReplayTransport`], which serves them back. Together they allow
+//! deterministic tests without a mock server.
+use std::{
+    fmt::Debug,
+    fs,
+    future::Future,
+    path::{Path, PathBuf},
+    pin::Pin,
+    sync::{Arc, OnceLock},
+    time::Duration,
+};
+
+use reqwest::StatusCode;
+
+use crate::treasury::{AuctionResult, AuctionResultError, RateLimiter};
+
+/// The future returned by [`Transport::fetch_async`].
+pub type TransportFuture<'a> = Pin<Box<dyn Future<Output = AuctionResult<String>> + Send + 'a>>;
+
+/// The trait that all transports must implement.
+pub trait Transport: Debug + Send + Sync {
+    /// Return the body of the given url. By default the future of
+    /// [`Transport::fetch_async`] is driven to completion on the current
+    /// thread, which works for every transport that does not need a runtime.
+    fn fetch(&self, url: &str) -> AuctionResult<String> {
+        futures::executor::block_on(self.fetch_async(url))
+    }
+
+    /// Return the body of the given url without blocking the current thread.
+    fn fetch_async<'a>(&'a self, url: &'a str) -> TransportFuture<'a>;
+}
+
+/// The transport that sends the requests to the api. The http clients are
+/// created on first use and pooled for every following request. Without the
+/// `blocking` feature the requests need the tokio runtime of
+/// [`Transport::fetch_async`].
+#[derive(Debug)]
+pub struct HttpTransport {
+    timeout: Duration,
+    user_agent: String,
+    proxy: Option<String>,
+    rate_limiter: Arc<RateLimiter>,
+    #[cfg(feature = "blocking")]
+    blocking_client: OnceLock<reqwest::blocking::Client>,
+    async_client: OnceLock<reqwest::Client>,
+}
+
+impl HttpTransport {
+    /// Create a new transport with the given timeout and user agent.
+    pub fn new(timeout: Duration, user_agent: impl Into<String>) -> Self {
+        Self {
+            timeout,
+            user_agent: user_agent.into(),
+            proxy: None,
+            rate_limiter: Arc::new(RateLimiter::default()),
+            #[cfg(feature = "blocking")]
+            blocking_client: OnceLock::new(),
+            async_client: OnceLock::new(),
+        }
+    }
+
+    /// Return the transport sending all requests through the given proxy.
+    pub fn with_proxy(mut self, proxy: impl Into<String>) -> Self {
+        self.proxy = Some(proxy.into());
+        self
+    }
+
+    /// Return the transport spacing its requests with the given rate limiter.
+    pub fn with_rate_limiter(mut self, rate_limiter: Arc<RateLimiter>) -> Self {
+        self.rate_limiter = rate_limiter;
+        self
+    }
+
+    /// Return the pooled blocking http client.
+    #[cfg(feature = "blocking")]
+    fn blocking_client(&self) -> AuctionResult<&reqwest::blocking::Client> {
+        if let Some(client) = self.blocking_client.get() {
+            return Ok(client);
+        }
+
+        let mut builder = reqwest::blocking::Client::builder()
+            .timeout(self.timeout)
+            .user_agent(&self.user_agent);
+
+        if let Some(proxy) = &self.proxy {
+            builder = builder.proxy(reqwest::Proxy::all(proxy)?);
+        }
+
+        let client = builder.build()?;
+        Ok(self.blocking_client.get_or_init(|| client))
+    }
+
+    /// Return the pooled non blocking http client.
+    fn async_client(&self) -> AuctionResult<&reqwest::Client> {
+        if let Some(client) = self.async_client.get() {
+            return Ok(client);
+        }
+
+        let mut builder = reqwest::Client::builder()
+            .timeout(self.timeout)
+            .user_agent(&self.user_agent);
+
+        if let Some(proxy) = &self.proxy {
+            builder = builder.proxy(reqwest::Proxy::all(proxy)?);
+        }
+
+        let client = builder.build()?;
+        Ok(self.async_client.get_or_init(|| client))
+    }
+}
+
+impl Transport for HttpTransport {
+    #[cfg(feature = "blocking")]
+    fn fetch(&self, url: &str) -> AuctionResult<String> {
+        std::thread::sleep(self.rate_limiter.reserve());
+
+        let response = self.blocking_client()?.get(url).send()?;
+        let status = response.status();
+
+        check_status(url, status, response.text()?)
+    }
+
+    fn fetch_async<'a>(&'a self, url: &'a str) -> TransportFuture<'a> {
+        Box::pin(async move {
+            tokio::time::sleep(self.rate_limiter.reserve()).await;
+
+            let response = self.async_client()?.get(url).send().await?;
+            let status = response.status();
+
+            check_status(url, status, response.text().await?)
+        })
+    }
+}
+
+/// Return the body of a successful response, otherwise the status with the body.
+fn check_status(url: &str, status: StatusCode, body: String) -> AuctionResult<String> {
+    if status.is_success() {
+        Ok(body)
+    } else {
+        Err(AuctionResultError::Status {
+            status,
+            url: url.to_owned(),
+            body,
+        })
+    }
+}
+
+/// The transport that saves every response of another transport to a fixtures directory.
+#[derive(Debug)]
+pub struct RecordingTransport {
+    dir: PathBuf,
+    inner: Arc<dyn Transport>,
+}
+
+impl RecordingTransport {
+    /// Record the responses of the given transport into the given directory.
+    pub fn new(dir: impl Into<PathBuf>, inner: Arc<dyn Transport>) -> Self {
+        Self {
+            dir: dir.into(),
+            inner,
+        }
+    }
+
+    /// Save the body of the url as fixture.
+    fn record(&self, url: &str, body: &str) -> AuctionResult<()> {
+        fs::create_dir_all(&self.dir)
+            .and_then(|_| fs::write(fixture_path(&self.dir, url), body))
+            .map_err(|_| AuctionResultError::Fixture(url.to_owned()))
+    }
+}
+
+impl Transport for RecordingTransport {
+    fn fetch(&self, url: &str) -> AuctionResult<String> {
+        let body = self.inner.fetch(url)?;
+        self.record(url, &body)?;
+
+        Ok(body)
+    }
+
+    fn fetch_async<'a>(&'a self, url: &'a str) -> TransportFuture<'a> {
+        Box::pin(async move {
+            let body = self.inner.fetch_async(url).await?;
+            self.record(url, &body)?;
+
+            Ok(body)
+        })
+    }
+}
+
+/// The transport that serves the responses saved by a [`RecordingTransport`].
+#[derive(Debug)]
+pub struct ReplayTransport {
+    dir: PathBuf,
+}
+
+impl ReplayTransport {
+    /// Serve the fixtures of the given directory.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// Return the saved body of the url.
+    fn replay(&self, url: &str) -> AuctionResult<String> {
+        fs::read_to_string(fixture_path(&self.dir, url))
+            .map_err(|_| AuctionResultError::Fixture(url.to_owned()))
+    }
+}
+
+impl Transport for ReplayTransport {
+    fn fetch(&self, url: &str) -> AuctionResult<String> {
+        self.replay(url)
+    }
+
+    fn fetch_async<'a>(&'a self, url: &'a str) -> TransportFuture<'a> {
+        Box::pin(async move { self.replay(url) })
+    }
+}
+
+/// Return the path of the fixture of the given url. The name is derived from
+/// the path and the query of the url, so that fixtures do not depend on the host.
+pub fn fixture_path(dir: &Path, url: &str) -> PathBuf {
+    let without_scheme = url.split_once("://").map_or(url, |(_, rest)| rest);
+    let path = without_scheme
+        .find('/')
+        .map_or("", |index| &without_scheme[index + 1..]);
+
+    let name = path
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '=' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect::<String>();
+
+    dir.join(format!("{}.json", name))
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::Matcher;
+
+    use super::*;
+    use crate::{
+        client::TreasuryClient,
+        get::TREASURIES_URL,
+        tests::fixture::api_single_item,
+        treasury::{AsyncTreasuryAccess, RetryPolicy},
+        Get,
+    };
+
+    const TEST_CUSIP: &str = "91282CJQ5";
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "auctionresult-fixtures-{}-{}",
+            name,
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn it_should_derive_the_fixture_name_from_path_and_query() {
+        let dir = Path::new("fixtures");
+
+        assert_eq!(
+            dir.join("TA_WS_securities_search_cusip=91282CJQ5_format=json.json"),
+            fixture_path(
+                dir,
+                "https://www.treasurydirect.gov/TA_WS/securities/search?cusip=91282CJQ5&format=json"
+            )
+        );
+        assert_eq!(
+            fixture_path(dir, "http://127.0.0.1:1234/securities/auctioned?days=8"),
+            fixture_path(dir, "http://localhost:80/securities/auctioned?days=8")
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_replay_recorded_responses() {
+        let mut server = mockito::Server::new_async().await;
+        let dir = temp_dir("replay");
+
+        let mock = server
+            .mock("GET", TREASURIES_URL)
+            .match_query(Matcher::UrlEncoded("cusip".into(), TEST_CUSIP.into()))
+            .with_body(api_single_item())
+            .expect(1)
+            .create_async()
+            .await;
+
+        let http = Arc::new(HttpTransport::new(Duration::from_secs(5), "test"));
+        let recording = TreasuryClient::builder()
+            .base_url(server.url())
+            .transport(Arc::new(RecordingTransport::new(&dir, http)))
+            .build()
+            .unwrap();
+
+        let recorded = Get::with_client(&recording, TEST_CUSIP)
+            .get_async()
+            .await
+            .unwrap();
+
+        let replaying = TreasuryClient::builder()
+            .base_url(server.url())
+            .transport(Arc::new(ReplayTransport::new(&dir)))
+            .build()
+            .unwrap();
+
+        let replayed = Get::with_client(&replaying, TEST_CUSIP)
+            .get_async()
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(recorded[0].cusip(), replayed[0].cusip());
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_on_a_missing_fixture() {
+        let client = TreasuryClient::builder()
+            .transport(Arc::new(ReplayTransport::new(temp_dir("missing"))))
+            .retry(RetryPolicy::new(3))
+            .build()
+            .unwrap();
+
+        let result = Get::with_client(&client, TEST_CUSIP).get_async().await;
+
+        assert!(matches!(result, Err(AuctionResultError::Fixture(_))));
+    }
+
+    #[test]
+    fn it_should_fetch_through_the_future_by_default() {
+        #[derive(Debug)]
+        struct Static;
+
+        impl Transport for Static {
+            fn fetch_async<'a>(&'a self, _url: &'a str) -> TransportFuture<'a> {
+                Box::pin(async { Ok("[]".to_owned()) })
+            }
+        }
+
+        assert_eq!("[]", Static.fetch("https://localhost/").unwrap());
+    }
+}
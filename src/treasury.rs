@@ -3,82 +3,407 @@
 #[allow(dead_code)]
 // make usable.
 mod deserializer;
+mod fields;
 
 // Make visible
+pub mod cache;
 pub mod error;
 pub mod load;
+pub(crate) mod pager;
 pub mod print;
+pub mod retry;
 pub mod security_type;
 
 use chrono::NaiveDateTime;
-use serde::Deserialize;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::future::Future;
+
+use crate::cusip::Cusip;
+use crate::tenor::Tenor;
 
 // Own serializers for special treasury specific types.
 use deserializer::bool_from_string;
-use deserializer::f64_from_string;
+use deserializer::option_bool_from_string;
+use deserializer::option_datetime_from_string;
+use deserializer::option_decimal_from_string;
+use deserializer::option_string;
+use deserializer::option_u32_from_string;
 
 // Re-Export
+pub use cache::{Cache, CacheMode};
 pub use error::AuctionResultError;
+#[cfg(feature = "blocking")]
 pub use load::load;
+pub use load::{decode, load_async};
+pub use retry::{RateLimiter, RetryPolicy};
 pub use security_type::SecurityType;
 
 const DEFAULT_SECURITY_DATE_FORMAT: &str = "%m/%d/%Y";
 
+/// The number of basis points of a percentage point.
+pub(crate) const BASIS_POINTS: Decimal = Decimal::ONE_HUNDRED;
+
 #[allow(dead_code)]
-#[derive(Debug, Default, Deserialize, Clone)]
+#[derive(Debug, Default, Deserialize, Serialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Treasury {
     // 1
-    cusip: String,
+    cusip: Cusip,
     // 2
     issue_date: NaiveDateTime,
     // 3
-    #[serde(rename(deserialize = "type"))]
-    security_type: SecurityType,
+    #[serde(rename = "securityType", default, deserialize_with = "option_string")]
+    security_type_name: Option<String>,
     // 4
     security_term: String,
     // 5
     maturity_date: NaiveDateTime,
     // 6
-    #[serde(deserialize_with = "f64_from_string")]
-    interest_rate: f64,
+    #[serde(default, deserialize_with = "option_decimal_from_string")]
+    interest_rate: Option<Decimal>,
+    // 7
+    #[serde(default, deserialize_with = "option_decimal_from_string")]
+    ref_cpi_on_issue_date: Option<Decimal>,
+    // 8
+    #[serde(default, deserialize_with = "option_decimal_from_string")]
+    ref_cpi_on_dated_date: Option<Decimal>,
+    // 9
+    #[serde(default, deserialize_with = "option_datetime_from_string")]
+    announcement_date: Option<NaiveDateTime>,
+    // 10
+    #[serde(default, deserialize_with = "option_datetime_from_string")]
+    auction_date: Option<NaiveDateTime>,
+    // 11
+    #[serde(default, deserialize_with = "option_u32_from_string")]
+    auction_date_year: Option<u32>,
+    // 12
+    #[serde(default, deserialize_with = "option_datetime_from_string")]
+    dated_date: Option<NaiveDateTime>,
+    // 13
+    #[serde(default, deserialize_with = "option_decimal_from_string")]
+    accrued_interest_per1000: Option<Decimal>,
+    // 14
+    #[serde(default, deserialize_with = "option_decimal_from_string")]
+    accrued_interest_per100: Option<Decimal>,
+    // 15
+    #[serde(default, deserialize_with = "option_decimal_from_string")]
+    adjusted_accrued_interest_per1000: Option<Decimal>,
+    // 16
+    #[serde(default, deserialize_with = "option_decimal_from_string")]
+    adjusted_price: Option<Decimal>,
+    // 17
+    #[serde(default, deserialize_with = "option_decimal_from_string")]
+    allocation_percentage: Option<Decimal>,
+    // 18
+    #[serde(default, deserialize_with = "option_u32_from_string")]
+    allocation_percentage_decimals: Option<u32>,
+    // 19
+    #[serde(default, deserialize_with = "option_string")]
+    announced_cusip: Option<String>,
+    // 20
+    #[serde(default, deserialize_with = "option_string")]
+    auction_format: Option<String>,
+    // 21
+    #[serde(default, deserialize_with = "option_decimal_from_string")]
+    average_median_discount_rate: Option<Decimal>,
+    // 22
+    #[serde(default, deserialize_with = "option_decimal_from_string")]
+    average_median_investment_rate: Option<Decimal>,
+    // 23
+    #[serde(default, deserialize_with = "option_decimal_from_string")]
+    average_median_price: Option<Decimal>,
+    // 24
+    #[serde(default, deserialize_with = "option_decimal_from_string")]
+    average_median_discount_margin: Option<Decimal>,
+    // 25
+    #[serde(default, deserialize_with = "option_decimal_from_string")]
+    average_median_yield: Option<Decimal>,
+    // 26
+    #[serde(default, deserialize_with = "option_bool_from_string")]
+    back_dated: Option<bool>,
+    // 27
+    #[serde(default, deserialize_with = "option_datetime_from_string")]
+    back_dated_date: Option<NaiveDateTime>,
     // 28
-    #[serde(deserialize_with = "f64_from_string")]
-    bid_to_cover_ratio: f64,
+    #[serde(default, deserialize_with = "option_decimal_from_string")]
+    bid_to_cover_ratio: Option<Decimal>,
+    // 29
+    #[serde(default, deserialize_with = "option_datetime_from_string")]
+    call_date: Option<NaiveDateTime>,
+    // 30
+    #[serde(default, deserialize_with = "option_bool_from_string")]
+    callable: Option<bool>,
+    // 31
+    #[serde(default, deserialize_with = "option_datetime_from_string")]
+    called_date: Option<NaiveDateTime>,
+    // 32
+    #[serde(rename = "cashManagementBillCMB", default, deserialize_with = "option_bool_from_string")]
+    cash_management_bill_cmb: Option<bool>,
+    // 33
+    #[serde(default, deserialize_with = "option_string")]
+    closing_time_competitive: Option<String>,
+    // 34
+    #[serde(default, deserialize_with = "option_string")]
+    closing_time_noncompetitive: Option<String>,
     // 35
-    #[serde(deserialize_with = "f64_from_string")]
-    competitive_accepted: f64,
+    #[serde(default, deserialize_with = "option_decimal_from_string")]
+    competitive_accepted: Option<Decimal>,
+    // 36
+    #[serde(default, deserialize_with = "option_u32_from_string")]
+    competitive_bid_decimals: Option<u32>,
+    // 37
+    #[serde(default, deserialize_with = "option_decimal_from_string")]
+    competitive_tendered: Option<Decimal>,
+    // 38
+    #[serde(default, deserialize_with = "option_bool_from_string")]
+    competitive_tenders_accepted: Option<bool>,
+    // 39
+    #[serde(default, deserialize_with = "option_string")]
+    corpus_cusip: Option<String>,
+    // 40
+    #[serde(default, deserialize_with = "option_string")]
+    cpi_base_reference_period: Option<String>,
+    // 41
+    #[serde(default, deserialize_with = "option_decimal_from_string")]
+    currently_outstanding: Option<Decimal>,
     // 42
-    #[serde(deserialize_with = "f64_from_string")]
-    direct_bidder_accepted: f64,
+    #[serde(default, deserialize_with = "option_decimal_from_string")]
+    direct_bidder_accepted: Option<Decimal>,
+    // 43
+    #[serde(default, deserialize_with = "option_decimal_from_string")]
+    direct_bidder_tendered: Option<Decimal>,
+    // 44
+    #[serde(default, deserialize_with = "option_decimal_from_string")]
+    estimated_amount_of_publicly_held_maturing_securities_by_type: Option<Decimal>,
+    // 45
+    #[serde(default, deserialize_with = "option_bool_from_string")]
+    fima_included: Option<bool>,
+    // 46
+    #[serde(default, deserialize_with = "option_decimal_from_string")]
+    fima_noncompetitive_accepted: Option<Decimal>,
+    // 47
+    #[serde(default, deserialize_with = "option_decimal_from_string")]
+    fima_noncompetitive_tendered: Option<Decimal>,
+    // 48
+    #[serde(default, deserialize_with = "option_string")]
+    first_interest_period: Option<String>,
+    // 49
+    #[serde(default, deserialize_with = "option_datetime_from_string")]
+    first_interest_payment_date: Option<NaiveDateTime>,
+    // 50
+    #[serde(default, deserialize_with = "option_bool_from_string")]
+    floating_rate: Option<bool>,
+    // 51
+    #[serde(default, deserialize_with = "option_datetime_from_string")]
+    frn_index_determination_date: Option<NaiveDateTime>,
+    // 52
+    #[serde(default, deserialize_with = "option_decimal_from_string")]
+    frn_index_determination_rate: Option<Decimal>,
     // 53
-    #[serde(deserialize_with = "f64_from_string")]
-    high_discount_rate: f64,
+    #[serde(default, deserialize_with = "option_decimal_from_string")]
+    high_discount_rate: Option<Decimal>,
     // 54
-    #[serde(deserialize_with = "f64_from_string")]
-    high_investment_rate: f64,
+    #[serde(default, deserialize_with = "option_decimal_from_string")]
+    high_investment_rate: Option<Decimal>,
+    // 55
+    #[serde(default, deserialize_with = "option_decimal_from_string")]
+    high_price: Option<Decimal>,
+    // 56
+    #[serde(default, deserialize_with = "option_decimal_from_string")]
+    high_discount_margin: Option<Decimal>,
     // 57
-    #[serde(deserialize_with = "f64_from_string")]
-    high_yield: f64,
+    #[serde(default, deserialize_with = "option_decimal_from_string")]
+    high_yield: Option<Decimal>,
+    // 58
+    #[serde(default, deserialize_with = "option_decimal_from_string")]
+    index_ratio_on_issue_date: Option<Decimal>,
     // 59
-    #[serde(deserialize_with = "f64_from_string")]
-    indirect_bidder_accepted: f64,
+    #[serde(default, deserialize_with = "option_decimal_from_string")]
+    indirect_bidder_accepted: Option<Decimal>,
+    // 60
+    #[serde(default, deserialize_with = "option_decimal_from_string")]
+    indirect_bidder_tendered: Option<Decimal>,
+    // 61
+    #[serde(default, deserialize_with = "option_string")]
+    interest_payment_frequency: Option<String>,
+    // 62
+    #[serde(default, deserialize_with = "option_decimal_from_string")]
+    low_discount_rate: Option<Decimal>,
+    // 63
+    #[serde(default, deserialize_with = "option_decimal_from_string")]
+    low_investment_rate: Option<Decimal>,
+    // 64
+    #[serde(default, deserialize_with = "option_decimal_from_string")]
+    low_price: Option<Decimal>,
+    // 65
+    #[serde(default, deserialize_with = "option_decimal_from_string")]
+    low_discount_margin: Option<Decimal>,
+    // 66
+    #[serde(default, deserialize_with = "option_decimal_from_string")]
+    low_yield: Option<Decimal>,
+    // 67
+    #[serde(default, deserialize_with = "option_datetime_from_string")]
+    maturing_date: Option<NaiveDateTime>,
+    // 68
+    #[serde(default, deserialize_with = "option_decimal_from_string")]
+    maximum_competitive_award: Option<Decimal>,
+    // 69
+    #[serde(default, deserialize_with = "option_decimal_from_string")]
+    maximum_noncompetitive_award: Option<Decimal>,
+    // 70
+    #[serde(default, deserialize_with = "option_decimal_from_string")]
+    maximum_single_bid: Option<Decimal>,
+    // 71
+    #[serde(default, deserialize_with = "option_decimal_from_string")]
+    minimum_bid_amount: Option<Decimal>,
+    // 72
+    #[serde(default, deserialize_with = "option_decimal_from_string")]
+    minimum_strip_amount: Option<Decimal>,
+    // 73
+    #[serde(default, deserialize_with = "option_decimal_from_string")]
+    minimum_to_issue: Option<Decimal>,
+    // 74
+    #[serde(default, deserialize_with = "option_decimal_from_string")]
+    multiples_to_bid: Option<Decimal>,
+    // 75
+    #[serde(default, deserialize_with = "option_decimal_from_string")]
+    multiples_to_issue: Option<Decimal>,
+    // 76
+    #[serde(default, deserialize_with = "option_decimal_from_string")]
+    nlp_exclusion_amount: Option<Decimal>,
+    // 77
+    #[serde(default, deserialize_with = "option_decimal_from_string")]
+    nlp_reporting_threshold: Option<Decimal>,
+    // 78
+    #[serde(default, deserialize_with = "option_decimal_from_string")]
+    noncompetitive_accepted: Option<Decimal>,
+    // 79
+    #[serde(default, deserialize_with = "option_bool_from_string")]
+    noncompetitive_tenders_accepted: Option<bool>,
+    // 80
+    #[serde(default, deserialize_with = "option_decimal_from_string")]
+    offering_amount: Option<Decimal>,
+    // 81
+    #[serde(default, deserialize_with = "option_string")]
+    original_cusip: Option<String>,
+    // 82
+    #[serde(default, deserialize_with = "option_datetime_from_string")]
+    original_dated_date: Option<NaiveDateTime>,
+    // 83
+    #[serde(default, deserialize_with = "option_datetime_from_string")]
+    original_issue_date: Option<NaiveDateTime>,
     // 84
     original_security_term: String,
+    // 85
+    #[serde(default, deserialize_with = "option_string")]
+    pdf_filename_announcement: Option<String>,
+    // 86
+    #[serde(default, deserialize_with = "option_string")]
+    pdf_filename_competitive_results: Option<String>,
+    // 87
+    #[serde(default, deserialize_with = "option_string")]
+    pdf_filename_noncompetitive_results: Option<String>,
+    // 88
+    #[serde(default, deserialize_with = "option_string")]
+    pdf_filename_special_announcement: Option<String>,
+    // 89
+    #[serde(default, deserialize_with = "option_decimal_from_string")]
+    price_per100: Option<Decimal>,
     // 90
-    #[serde(deserialize_with = "f64_from_string")]
-    primary_dealer_accepted: f64,
+    #[serde(default, deserialize_with = "option_decimal_from_string")]
+    primary_dealer_accepted: Option<Decimal>,
+    // 91
+    #[serde(default, deserialize_with = "option_decimal_from_string")]
+    primary_dealer_tendered: Option<Decimal>,
     // 92
     #[serde(deserialize_with = "bool_from_string")]
     reopening: bool,
+    // 93
+    #[serde(default, deserialize_with = "option_string")]
+    security_term_day_month: Option<String>,
+    // 94
+    #[serde(default, deserialize_with = "option_string")]
+    security_term_week_year: Option<String>,
+    // 95
+    #[serde(default, deserialize_with = "option_string")]
+    series: Option<String>,
+    // 96
+    #[serde(default, deserialize_with = "option_decimal_from_string")]
+    soma_accepted: Option<Decimal>,
+    // 97
+    #[serde(default, deserialize_with = "option_decimal_from_string")]
+    soma_holdings: Option<Decimal>,
+    // 98
+    #[serde(default, deserialize_with = "option_bool_from_string")]
+    soma_included: Option<bool>,
+    // 99
+    #[serde(default, deserialize_with = "option_decimal_from_string")]
+    soma_tendered: Option<Decimal>,
+    // 100
+    #[serde(default, deserialize_with = "option_decimal_from_string")]
+    spread: Option<Decimal>,
+    // 101
+    #[serde(default, deserialize_with = "option_decimal_from_string")]
+    standard_interest_payment_per1000: Option<Decimal>,
+    // 102
+    #[serde(default, deserialize_with = "option_bool_from_string")]
+    strippable: Option<bool>,
     // 103
     term: String,
+    // 104
+    #[serde(default, deserialize_with = "option_decimal_from_string")]
+    tiin_conversion_factor_per1000: Option<Decimal>,
+    // 105
+    #[serde(default, deserialize_with = "option_bool_from_string")]
+    tips: Option<bool>,
     // 106
-    #[serde(deserialize_with = "f64_from_string")]
-    total_accepted: f64,
+    #[serde(default, deserialize_with = "option_decimal_from_string")]
+    total_accepted: Option<Decimal>,
+    // 107
+    #[serde(default, deserialize_with = "option_decimal_from_string")]
+    total_tendered: Option<Decimal>,
+    // 108
+    #[serde(default, deserialize_with = "option_decimal_from_string")]
+    treasury_retail_accepted: Option<Decimal>,
+    // 109
+    #[serde(default, deserialize_with = "option_bool_from_string")]
+    treasury_retail_tenders_accepted: Option<bool>,
+    // 110
+    #[serde(rename = "type")]
+    security_type: SecurityType,
+    // 111
+    #[serde(default, deserialize_with = "option_decimal_from_string")]
+    unadjusted_accrued_interest_per1000: Option<Decimal>,
+    // 112
+    #[serde(default, deserialize_with = "option_decimal_from_string")]
+    unadjusted_price: Option<Decimal>,
+    // 113
+    #[serde(default, deserialize_with = "option_datetime_from_string")]
+    updated_timestamp: Option<NaiveDateTime>,
+    // 114
+    #[serde(default, deserialize_with = "option_string")]
+    xml_filename_announcement: Option<String>,
+    // 115
+    #[serde(default, deserialize_with = "option_string")]
+    xml_filename_competitive_results: Option<String>,
+    // 116
+    #[serde(default, deserialize_with = "option_string")]
+    xml_filename_special_announcement: Option<String>,
+    // 117
+    #[serde(default, deserialize_with = "option_string")]
+    tint_cusip1: Option<String>,
+    // 118
+    #[serde(default, deserialize_with = "option_string")]
+    tint_cusip2: Option<String>,
+    // The when-issued yield at the auction, supplied by the user and not by the api.
+    #[serde(
+        default,
+        deserialize_with = "option_decimal_from_string",
+        skip_serializing_if = "Option::is_none"
+    )]
+    when_issued_yield: Option<Decimal>,
 }
-// serializ_dt() must be implemented.
-// #[serde(serialize_with = "serialize_dt", skip_serializing_if  = "Option::is_none")]
 
 impl Treasury {
     pub fn new() -> Self {
@@ -90,6 +415,11 @@ impl Treasury {
         self.cusip.as_str()
     }
 
+    /// Return the typed CUSIP number of the treasury.
+    pub fn get_cusip(&self) -> &Cusip {
+        &self.cusip
+    }
+
     /// Return if an auction is re-openend.
     pub fn is_reopening(&self) -> bool {
         self.reopening
@@ -99,6 +429,7 @@ impl Treasury {
     pub fn get_fields<'a>(&self) -> Vec<&'a str> {
         let mut fields = vec![
             "Security Term",
+            "Bucket",
             "CUSIP",
             "Reopening",
             "Security Type",
@@ -108,14 +439,32 @@ impl Treasury {
             "Dealers %",
             "Directs %",
             "Indirects %",
+            "Dealers Cover (Tendered %)",
+            "Directs Cover (Tendered %)",
+            "Indirects Cover (Tendered %)",
         ];
 
-        if self.security_type == SecurityType::Bill {
-            fields.push("High Rate");
-            fields.push("Investment Rate");
-        } else {
-            fields.push("High Yield");
-            fields.push("Interest Rate");
+        match self.get_security_type() {
+            SecurityType::Bill => {
+                fields.push("High Rate");
+                fields.push("Investment Rate");
+            }
+            SecurityType::Frn => {
+                fields.push("High Margin");
+                fields.push("Spread");
+                fields.push("Low Margin");
+                fields.push("Index Rate");
+            }
+            SecurityType::Tips => {
+                fields.push("Real Yield");
+                fields.push("Interest Rate");
+                fields.push("Ref CPI");
+                fields.push("Index Ratio");
+            }
+            _ => {
+                fields.push("High Yield");
+                fields.push("Interest Rate");
+            }
         }
 
         fields
@@ -141,33 +490,78 @@ impl Treasury {
         self.original_security_term.as_str()
     }
 
-    /// Get the security type of the treasury.
+    /// Return the benchmark bucket of the remaining term, i. e. 10-Year for a
+    /// reopening with a security term of 9-Year 10-Month.
+    pub fn get_bucket(&self) -> Option<Tenor> {
+        Tenor::bucket_of(self)
+    }
+
+    /// Get the security type of the treasury. TIPS are returned as [`SecurityType::Tips`],
+    /// although the api returns them as notes or bonds.
     pub fn get_security_type(&self) -> SecurityType {
-        self.security_type.to_owned()
+        if self.tips == Some(true) {
+            SecurityType::Tips
+        } else {
+            self.security_type.to_owned()
+        }
     }
 
-    /// Calculate the percentage of debt that was accepted by primary dealers.
-    pub fn get_percentage_debt_purchased_by_dealers(&self) -> f64 {
-        (self.primary_dealer_accepted / self.competitive_accepted) * 100.0
+    /// Calculate the percentage of debt that was accepted by primary dealers, [`None`]
+    /// if an amount is missing or nothing was accepted.
+    pub fn get_percentage_debt_purchased_by_dealers(&self) -> Option<Decimal> {
+        percentage(self.primary_dealer_accepted, self.competitive_accepted)
     }
 
-    /// Calculate the percentage of debt that was accepted by direct bidders.
-    pub fn get_percentage_debt_purchased_by_directs(&self) -> f64 {
-        (self.direct_bidder_accepted / self.competitive_accepted) * 100.0
+    /// Calculate the percentage of debt that was accepted by direct bidders, [`None`]
+    /// if an amount is missing or nothing was accepted.
+    pub fn get_percentage_debt_purchased_by_directs(&self) -> Option<Decimal> {
+        percentage(self.direct_bidder_accepted, self.competitive_accepted)
     }
 
-    /// Calculate the percentage of debt that was accepted by indirect bidders.
-    pub fn get_percentage_debt_purchased_by_indirects(&self) -> f64 {
-        (self.indirect_bidder_accepted / self.competitive_accepted) * 100.0
+    /// Calculate the percentage of debt that was accepted by indirect bidders, [`None`]
+    /// if an amount is missing or nothing was accepted.
+    pub fn get_percentage_debt_purchased_by_indirects(&self) -> Option<Decimal> {
+        percentage(self.indirect_bidder_accepted, self.competitive_accepted)
+    }
+
+    /// Calculate the cover ratio of the primary dealers, i. e. tendered / accepted.
+    pub fn get_dealers_cover_ratio(&self) -> Option<Decimal> {
+        ratio(self.primary_dealer_tendered, self.primary_dealer_accepted)
+    }
+
+    /// Calculate the cover ratio of the direct bidders, i. e. tendered / accepted.
+    pub fn get_directs_cover_ratio(&self) -> Option<Decimal> {
+        ratio(self.direct_bidder_tendered, self.direct_bidder_accepted)
+    }
+
+    /// Calculate the cover ratio of the indirect bidders, i. e. tendered / accepted.
+    /// A high indirect cover signals foreign demand.
+    pub fn get_indirects_cover_ratio(&self) -> Option<Decimal> {
+        ratio(self.indirect_bidder_tendered, self.indirect_bidder_accepted)
+    }
+
+    /// Calculate the percentage of the competitive tenders that came from primary dealers.
+    pub fn get_percentage_tendered_by_dealers(&self) -> Option<Decimal> {
+        percentage(self.primary_dealer_tendered, self.competitive_tendered)
+    }
+
+    /// Calculate the percentage of the competitive tenders that came from direct bidders.
+    pub fn get_percentage_tendered_by_directs(&self) -> Option<Decimal> {
+        percentage(self.direct_bidder_tendered, self.competitive_tendered)
+    }
+
+    /// Calculate the percentage of the competitive tenders that came from indirect bidders.
+    pub fn get_percentage_tendered_by_indirects(&self) -> Option<Decimal> {
+        percentage(self.indirect_bidder_tendered, self.competitive_tendered)
     }
 
     /// Returns the get bid to cover ratio of this [`Treasury`].
-    pub fn get_bid_to_cover_ratio(&self) -> f64 {
+    pub fn get_bid_to_cover_ratio(&self) -> Option<Decimal> {
         self.bid_to_cover_ratio
     }
 
     /// Returns the get high yield of this [`Treasury`].
-    pub fn get_high_yield(&self) -> f64 {
+    pub fn get_high_yield(&self) -> Option<Decimal> {
         if self.security_type == SecurityType::Bill {
             self.high_discount_rate
         } else {
@@ -175,13 +569,58 @@ impl Treasury {
         }
     }
 
+    /// Return the rate the auction cleared at: the high discount margin of a
+    /// FRN, the high discount rate of a bill and the high yield otherwise.
+    pub fn get_auction_rate(&self) -> Option<Decimal> {
+        if self.security_type == SecurityType::Frn {
+            self.high_discount_margin
+        } else {
+            self.get_high_yield()
+        }
+    }
+
+    /// Set the when-issued yield at the auction, i. e. at 1 pm, to compute the tail.
+    pub fn set_when_issued_yield(&mut self, when_issued_yield: Decimal) {
+        self.when_issued_yield = Some(when_issued_yield);
+    }
+
+    /// Return the when-issued yield at the auction, if one was supplied.
+    pub fn get_when_issued_yield(&self) -> Option<Decimal> {
+        self.when_issued_yield
+    }
+
+    /// Return the tail in basis points, the auction rate above the when-issued
+    /// yield. A negative tail is a stop-through. FRNs are auctioned at a
+    /// discount margin, not a yield, and have no tail.
+    pub fn get_tail(&self) -> Option<Decimal> {
+        if self.security_type == SecurityType::Frn {
+            return None;
+        }
+
+        self.get_auction_rate()
+            .zip(self.when_issued_yield)
+            .and_then(|(rate, when_issued)| rate.checked_sub(when_issued))
+            .and_then(|tail| tail.checked_mul(BASIS_POINTS))
+    }
+
+    /// Return the real yield of a TIPS, which the api returns as its high yield.
+    pub fn get_real_yield(&self) -> Option<Decimal> {
+        self.high_yield
+            .filter(|_| self.get_security_type() == SecurityType::Tips)
+    }
+
+    /// Return the auction date, if the api provides one.
+    pub fn get_auction_date(&self) -> Option<NaiveDateTime> {
+        self.auction_date
+    }
+
     /// Return the issue date.
     pub fn get_issue_date(&self) -> NaiveDateTime {
         self.issue_date
     }
 
     /// Returns the get high discount rate of this [`Treasury`].
-    pub fn get_interest_rate(&self) -> f64 {
+    pub fn get_interest_rate(&self) -> Option<Decimal> {
         if self.security_type == SecurityType::Bill {
             self.high_investment_rate
         } else {
@@ -190,6 +629,17 @@ impl Treasury {
     }
 }
 
+/// Return the ratio of the amounts, if both are known and the base is not zero.
+fn ratio(amount: Option<Decimal>, base: Option<Decimal>) -> Option<Decimal> {
+    amount.zip(base).and_then(|(amount, base)| amount.checked_div(base))
+}
+
+/// Return the amount as a percentage of the base. The amount is scaled before
+/// the division, so a percentage of whole dollar amounts is exact.
+fn percentage(amount: Option<Decimal>, base: Option<Decimal>) -> Option<Decimal> {
+    ratio(amount.and_then(|a| a.checked_mul(Decimal::ONE_HUNDRED)), base)
+}
+
 /// Define a convienience type for the return values.
 pub type AuctionResult<T> = std::result::Result<T, AuctionResultError>;
 
@@ -198,9 +648,19 @@ pub type Treasuries = Vec<Treasury>;
 
 /// The trait that all auction result modules must implement.
 pub trait TreasuryAccess<T> {
-    fn get(&self) -> AuctionResult<T>;
     fn url(&self) -> String;
 }
 
+/// The blocking access of the auction result modules.
+#[cfg(feature = "blocking")]
+pub trait BlockingTreasuryAccess<T>: TreasuryAccess<T> {
+    fn get(&self) -> AuctionResult<T>;
+}
+
+/// The non blocking counterpart of [`BlockingTreasuryAccess`].
+pub trait AsyncTreasuryAccess<T>: TreasuryAccess<T> {
+    fn get_async(&self) -> impl Future<Output = AuctionResult<T>> + Send;
+}
+
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,286 @@
+//! # A disk cache for the responses of the TreasuryDirect api.
+//!
+//! Every response body is stored in a file whose name is derived from the
+//! requested url. An entry is considered fresh as long as it is younger than
+//! the ttl of the endpoint the url points to.
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+
+use directories::ProjectDirs;
+
+/// The default time to live of the auctioned endpoint. Results are published
+/// during the day, so this must be short.
+const AUCTIONED_TTL: Duration = Duration::from_secs(15 * 60);
+
+/// The default time to live of the search endpoint.
+const SEARCH_TTL: Duration = Duration::from_secs(12 * 60 * 60);
+
+/// The default time to live of any other endpoint.
+const DEFAULT_TTL: Duration = Duration::from_secs(60 * 60);
+
+/// Determines how the cache is consulted.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum CacheMode {
+    /// Serve fresh entries from the cache, load everything else.
+    #[default]
+    Normal,
+    /// Serve only from the cache, regardless of the age of an entry.
+    Offline,
+    /// Always load and update the cache.
+    Refresh,
+}
+
+/// The endpoints of the api, used to look up the ttl of an url.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Endpoint {
+    Auctioned,
+    Search,
+    Other,
+}
+
+impl Endpoint {
+    /// Determine the endpoint from the given url.
+    pub fn from_url(url: &str) -> Self {
+        if url.contains("/securities/auctioned") {
+            Endpoint::Auctioned
+        } else if url.contains("/securities/search") {
+            Endpoint::Search
+        } else {
+            Endpoint::Other
+        }
+    }
+}
+
+/// Descriptor of the response cache.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cache {
+    dir: PathBuf,
+    mode: CacheMode,
+    auctioned_ttl: Duration,
+    search_ttl: Duration,
+    default_ttl: Duration,
+}
+
+impl Cache {
+    /// Create a new cache that stores its entries in the given directory.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            mode: CacheMode::default(),
+            auctioned_ttl: AUCTIONED_TTL,
+            search_ttl: SEARCH_TTL,
+            default_ttl: DEFAULT_TTL,
+        }
+    }
+
+    /// Return the platform specific cache directory of this application.
+    pub fn default_dir() -> Option<PathBuf> {
+        ProjectDirs::from("", "", "auctionresult").map(|dirs| dirs.cache_dir().to_path_buf())
+    }
+
+    /// Return the cache with the given mode.
+    pub fn with_mode(mut self, mode: CacheMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Return the mode of the cache.
+    pub fn mode(&self) -> CacheMode {
+        self.mode
+    }
+
+    /// Return the directory of the cache.
+    pub fn dir(&self) -> &Path {
+        self.dir.as_path()
+    }
+
+    /// Set the time to live of the entries of the given endpoint.
+    pub fn set_ttl(&mut self, endpoint: Endpoint, ttl: Duration) {
+        match endpoint {
+            Endpoint::Auctioned => self.auctioned_ttl = ttl,
+            Endpoint::Search => self.search_ttl = ttl,
+            Endpoint::Other => self.default_ttl = ttl,
+        }
+    }
+
+    /// Return the time to live of the entries of the given endpoint.
+    pub fn ttl(&self, endpoint: Endpoint) -> Duration {
+        match endpoint {
+            Endpoint::Auctioned => self.auctioned_ttl,
+            Endpoint::Search => self.search_ttl,
+            Endpoint::Other => self.default_ttl,
+        }
+    }
+
+    /// Return the path of the entry for the given url.
+    pub fn path(&self, url: &str) -> PathBuf {
+        self.dir
+            .join(format!("{:016x}.json", fnv1a(url.as_bytes())))
+    }
+
+    /// Return the cached body of the given url. Stale entries are only
+    /// returned in [`CacheMode::Offline`].
+    pub fn read(&self, url: &str) -> Option<String> {
+        let path = self.path(url);
+
+        if self.mode != CacheMode::Offline {
+            let modified = fs::metadata(&path).and_then(|m| m.modified()).ok()?;
+            let age = SystemTime::now()
+                .duration_since(modified)
+                .unwrap_or_default();
+
+            if age >= self.ttl(Endpoint::from_url(url)) {
+                return None;
+            }
+        }
+
+        fs::read_to_string(path).ok()
+    }
+
+    /// Store the body of the given url.
+    pub fn write(&self, url: &str, body: &str) -> io::Result<()> {
+        fs::create_dir_all(&self.dir)?;
+        fs::write(self.path(url), body)
+    }
+}
+
+/// The 64 bit FNV-1a hash, which is stable across builds unlike the hasher of the std lib.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x100000001b3)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::Matcher;
+
+    use super::*;
+    use crate::{
+        client::TreasuryClient,
+        get::TREASURIES_URL,
+        tests::fixture::api_single_item,
+        treasury::{load::load_async, AuctionResultError},
+    };
+    #[cfg(feature = "blocking")]
+    use crate::treasury::load::load;
+
+    const TEST_URL: &str =
+        "https://www.treasurydirect.gov/TA_WS/securities/search?cusip=91282CJQ5&format=json";
+
+    fn temp_cache(name: &str) -> Cache {
+        let dir =
+            std::env::temp_dir().join(format!("auctionresult-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        Cache::new(dir)
+    }
+
+    #[test]
+    fn it_should_determine_the_endpoint_of_an_url() {
+        assert_eq!(Endpoint::Search, Endpoint::from_url(TEST_URL));
+        assert_eq!(
+            Endpoint::Auctioned,
+            Endpoint::from_url("/securities/auctioned?days=8")
+        );
+        assert_eq!(Endpoint::Other, Endpoint::from_url("/securities/upcoming"));
+    }
+
+    #[test]
+    fn it_should_read_a_fresh_entry() {
+        let cache = temp_cache("fresh");
+
+        assert!(cache.read(TEST_URL).is_none());
+        cache.write(TEST_URL, "[]").unwrap();
+        assert_eq!(Some("[]".to_owned()), cache.read(TEST_URL));
+    }
+
+    #[test]
+    fn it_should_only_serve_stale_entries_when_offline() {
+        let mut cache = temp_cache("stale");
+        cache.set_ttl(Endpoint::Search, Duration::ZERO);
+        cache.write(TEST_URL, "[]").unwrap();
+
+        assert!(cache.read(TEST_URL).is_none());
+        assert!(cache.with_mode(CacheMode::Offline).read(TEST_URL).is_some());
+    }
+
+    #[test]
+    #[cfg(feature = "blocking")]
+    fn it_should_fail_when_offline_and_not_cached() {
+        let cache = temp_cache("offline").with_mode(CacheMode::Offline);
+        let client = TreasuryClient::builder().cache(cache).build().unwrap();
+        let result = load(&client, TEST_URL);
+
+        assert!(matches!(result, Err(AuctionResultError::NotCached(_))));
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_when_offline_and_not_cached_async() {
+        let cache = temp_cache("offline-async").with_mode(CacheMode::Offline);
+        let client = TreasuryClient::builder().cache(cache).build().unwrap();
+        let result = load_async(&client, TEST_URL).await;
+
+        assert!(matches!(result, Err(AuctionResultError::NotCached(_))));
+    }
+
+    #[test]
+    #[cfg(feature = "blocking")]
+    fn it_should_load_only_once_unless_refreshing() {
+        let mut server = mockito::Server::new();
+        let url = format!(
+            "{}{}?cusip=91282CJQ5&format=json",
+            server.url(),
+            TREASURIES_URL
+        );
+        let cache = temp_cache("refresh");
+
+        let mock = server
+            .mock("GET", TREASURIES_URL)
+            .match_query(Matcher::Any)
+            .with_body(api_single_item())
+            .expect(2)
+            .create();
+
+        let refresh = cache.clone().with_mode(CacheMode::Refresh);
+        let client = TreasuryClient::builder().cache(cache).build().unwrap();
+        let refresh_client = TreasuryClient::builder().cache(refresh).build().unwrap();
+
+        load(&client, url.as_str()).unwrap();
+        load(&client, url.as_str()).unwrap();
+        load(&refresh_client, url.as_str()).unwrap();
+
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn it_should_load_only_once_unless_refreshing_async() {
+        let mut server = mockito::Server::new_async().await;
+        let url = format!(
+            "{}{}?cusip=91282CJQ5&format=json",
+            server.url(),
+            TREASURIES_URL
+        );
+        let cache = temp_cache("refresh-async");
+
+        let mock = server
+            .mock("GET", TREASURIES_URL)
+            .match_query(Matcher::Any)
+            .with_body(api_single_item())
+            .expect(2)
+            .create_async()
+            .await;
+
+        let refresh = cache.clone().with_mode(CacheMode::Refresh);
+        let client = TreasuryClient::builder().cache(cache).build().unwrap();
+        let refresh_client = TreasuryClient::builder().cache(refresh).build().unwrap();
+
+        load_async(&client, url.as_str()).await.unwrap();
+        load_async(&client, url.as_str()).await.unwrap();
+        load_async(&refresh_client, url.as_str()).await.unwrap();
+
+        mock.assert_async().await;
+    }
+}
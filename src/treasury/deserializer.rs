@@ -1,30 +1,98 @@
 //! # Special desericalizers for the treasery json schema.
 //!
 //!
+use std::str::FromStr;
+
+use chrono::NaiveDateTime;
+use rust_decimal::Decimal;
 use serde::{de, Deserialize};
 use serde_json::Value;
 
-/// Deserialize bool from String with custom value mapping
+/// The format of the dates of the api.
+const DATE_TIME_FORMAT: &str = "%Y-%m-%dT%H:%M:%S";
+
+/// Deserialize bool from String with custom value mapping. A serialized
+/// treasury stores a plain bool.
 pub fn bool_from_string<'de, D>(deserializer: D) -> Result<bool, D::Error>
 where
     D: de::Deserializer<'de>,
 {
-    match String::deserialize(deserializer)?.as_ref() {
-        "Yes" => Ok(true),
-        "No" => Ok(false),
-        other => Err(de::Error::invalid_value(
-            de::Unexpected::Str(other),
+    match Value::deserialize(deserializer)? {
+        Value::Bool(b) => Ok(b),
+        Value::String(s) if s == "Yes" => Ok(true),
+        Value::String(s) if s == "No" => Ok(false),
+        Value::String(s) => Err(de::Error::invalid_value(
+            de::Unexpected::Str(&s),
             &"Yes or No",
         )),
+        _ => Err(de::Error::custom("wrong type")),
+    }
+}
+
+/// Parse a decimal exactly, the api writes some large amounts in scientific notation.
+fn parse_decimal(s: &str) -> Result<Decimal, rust_decimal::Error> {
+    Decimal::from_str(s).or_else(|_| Decimal::from_scientific(s))
+}
+
+/// Deserialize an optional string, an empty string is [`None`].
+pub fn option_string<'de, D: de::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<String>, D::Error> {
+    Ok(Option::<String>::deserialize(deserializer)?.filter(|s| !s.is_empty()))
+}
+
+/// Deserialize an optional decimal from a string, an empty string is [`None`].
+pub fn option_decimal_from_string<'de, D: de::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<Decimal>, D::Error> {
+    match Option::<Value>::deserialize(deserializer)? {
+        None | Some(Value::Null) => Ok(None),
+        Some(Value::String(s)) if s.is_empty() => Ok(None),
+        Some(Value::String(s)) => parse_decimal(&s).map(Some).map_err(de::Error::custom),
+        Some(Value::Number(num)) => parse_decimal(&num.to_string())
+            .map(Some)
+            .map_err(de::Error::custom),
+        _ => Err(de::Error::custom("wrong type")),
+    }
+}
+
+/// Deserialize an optional u32 from a string, an empty string is [`None`].
+pub fn option_u32_from_string<'de, D: de::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<u32>, D::Error> {
+    match Option::<Value>::deserialize(deserializer)? {
+        None | Some(Value::Null) => Ok(None),
+        Some(Value::String(s)) if s.is_empty() => Ok(None),
+        Some(Value::String(s)) => s.parse::<u32>().map(Some).map_err(de::Error::custom),
+        Some(Value::Number(num)) => num
+            .as_u64()
+            .and_then(|n| u32::try_from(n).ok())
+            .map(Some)
+            .ok_or_else(|| de::Error::custom("not an u32")),
+        _ => Err(de::Error::custom("wrong type")),
+    }
+}
+
+/// Deserialize an optional bool from "Yes" or "No", an empty string is [`None`].
+pub fn option_bool_from_string<'de, D: de::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<bool>, D::Error> {
+    match Option::<Value>::deserialize(deserializer)? {
+        None | Some(Value::Null) => Ok(None),
+        Some(Value::String(s)) if s.is_empty() => Ok(None),
+        Some(value) => bool_from_string(value)
+            .map(Some)
+            .map_err(de::Error::custom),
     }
 }
 
-pub fn f64_from_string<'de, D: de::Deserializer<'de>>(deserializer: D) -> Result<f64, D::Error> {
-    Ok(match Value::deserialize(deserializer)? {
-        Value::String(s) => s.parse::<f64>().unwrap_or(0.0),
-        Value::Number(num) => num.as_f64().unwrap(),
-        _ => return Err(de::Error::custom("wrong type")),
-    })
+/// Deserialize an optional date, an empty string is [`None`].
+pub fn option_datetime_from_string<'de, D: de::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<NaiveDateTime>, D::Error> {
+    option_string(deserializer)?
+        .map(|s| NaiveDateTime::parse_from_str(&s, DATE_TIME_FORMAT).map_err(de::Error::custom))
+        .transpose()
 }
 
 // const FORMAT: &str = "%Y-%m-%d %H:%M:%S";
@@ -1,21 +1,207 @@
 //! # The error used throughout this libray
 //!
-use std::any::Any;
+//! Every error has a human readable message, a stable exit code for the
+//! command line and, where there is one, the underlying error as its source.
+use std::{any::Any, error::Error, fmt};
+
+use reqwest::StatusCode;
+
+/// The number of characters of a response body shown in the message.
+const MAX_BODY_LENGTH: usize = 200;
 
 #[derive(Debug)]
 pub enum AuctionResultError {
+    // The request could not be sent or the response could not be read.
     Request(reqwest::Error),
+    // The thread of a blocking request panicked, with the payload of the panic.
     RequestDyn(Box<dyn Any + Send>),
+    // The api answered with an unsuccessful status.
+    Status {
+        status: StatusCode,
+        url: String,
+        body: String,
+    },
+    // The request timed out.
+    Timeout(reqwest::Error),
     // Could not parse cusip number.
     ParseCusip,
+    // The check digit of the cusip number is wrong, the suggestion has the right one.
+    CheckDigit {
+        cusip: String,
+        suggestion: String,
+    },
     ParseTenor,
-    NoTreasury,
-    OutOfBounds,
+    // The api returned no treasury.
+    EmptyResult,
+    // There are not enough auctions before the assessed auction.
+    InsufficientHistory {
+        required: usize,
+        available: usize,
+    },
+    // Could not decode the json response, the path names the failing field.
+    Decode {
+        path: String,
+        source: serde_json::Error,
+    },
+    // Offline mode and the url is not cached.
+    NotCached(String),
+    // The fixture of the url could not be read or written.
+    Fixture(String),
+    // A line of the when-issued yields could not be parsed.
+    ParseWhenIssued {
+        line: usize,
+    },
+    // The scoring profiles could not be parsed.
+    ParseProfile(toml::de::Error),
+    // The file of the scoring profiles could not be read.
+    ReadProfile(std::io::Error),
+    // There is no scoring profile with the name.
+    UnknownProfile(String),
+    // The lookback is neither a number of auctions nor a window.
+    ParseLookback(String),
+    // The request failed after the given number of attempts.
+    Retries {
+        attempts: u32,
+        source: Box<AuctionResultError>,
+    },
+}
+
+impl AuctionResultError {
+    /// Return the exit code of the command line. The codes are stable, a
+    /// failure after retries exits with the code of the last attempt.
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            AuctionResultError::Request(_) => 1,
+            AuctionResultError::RequestDyn(_) => 2,
+            AuctionResultError::ParseCusip | AuctionResultError::CheckDigit { .. } => 3,
+            AuctionResultError::ParseTenor => 4,
+            AuctionResultError::Decode { .. } => 5,
+            AuctionResultError::NotCached(_) => 6,
+            AuctionResultError::Fixture(_) => 7,
+            AuctionResultError::Status { .. } => 8,
+            AuctionResultError::Timeout(_) => 9,
+            AuctionResultError::EmptyResult => 10,
+            AuctionResultError::InsufficientHistory { .. } => 11,
+            AuctionResultError::ParseWhenIssued { .. } => 12,
+            AuctionResultError::ParseProfile(_) | AuctionResultError::ReadProfile(_) => 13,
+            AuctionResultError::UnknownProfile(_) => 14,
+            AuctionResultError::ParseLookback(_) => 15,
+            AuctionResultError::Retries { source, .. } => source.exit_code(),
+        }
+    }
+}
+
+impl fmt::Display for AuctionResultError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AuctionResultError::Request(_) => write!(f, "The request to the api failed"),
+            AuctionResultError::RequestDyn(_) => write!(f, "The thread of the request panicked"),
+            AuctionResultError::Status { status, url, body } => {
+                write!(f, "The api answered with status {} for {}", status, url)?;
+
+                match excerpt(body) {
+                    Some(excerpt) => write!(f, ": {}", excerpt),
+                    None => Ok(()),
+                }
+            }
+            AuctionResultError::Timeout(_) => write!(f, "The request to the api timed out"),
+            AuctionResultError::ParseCusip => write!(f, "Could not parse cusip number"),
+            AuctionResultError::CheckDigit { cusip, suggestion } => write!(
+                f,
+                "The check digit of the cusip number {} is wrong, did you mean {}",
+                cusip, suggestion
+            ),
+            AuctionResultError::ParseTenor => write!(f, "Could not parse tenor"),
+            AuctionResultError::EmptyResult => write!(f, "The api returned no treasury"),
+            AuctionResultError::InsufficientHistory {
+                required,
+                available,
+            } => write!(
+                f,
+                "Not enough auctions to look back: {} required, {} available",
+                required, available
+            ),
+            AuctionResultError::Decode { path, .. } => {
+                write!(f, "Could not decode the field {} of the response", path)
+            }
+            AuctionResultError::NotCached(url) => {
+                write!(f, "No cached result available for {}", url)
+            }
+            AuctionResultError::Fixture(url) => {
+                write!(f, "Could not read or write the fixture of {}", url)
+            }
+            AuctionResultError::ParseWhenIssued { line } => {
+                write!(f, "Could not parse the when-issued yield in line {}", line)
+            }
+            AuctionResultError::ParseProfile(_) => write!(f, "Could not parse the scoring profiles"),
+            AuctionResultError::ReadProfile(_) => write!(f, "Could not read the scoring profiles"),
+            AuctionResultError::UnknownProfile(name) => {
+                write!(f, "There is no scoring profile named {}", name)
+            }
+            AuctionResultError::ParseLookback(lookback) => write!(
+                f,
+                "Could not parse the lookback {}, expected a number of auctions or a window like 1y",
+                lookback
+            ),
+            AuctionResultError::Retries { attempts, .. } => {
+                write!(f, "Giving up after {} attempts", attempts)
+            }
+        }
+    }
+}
+
+/// Return the start of the response body on a single line, [`None`] if it is empty.
+fn excerpt(body: &str) -> Option<String> {
+    let body = body.split_whitespace().collect::<Vec<&str>>().join(" ");
+
+    if body.is_empty() {
+        return None;
+    }
+
+    match body.char_indices().nth(MAX_BODY_LENGTH) {
+        Some((end, _)) => Some(format!("{}...", &body[..end])),
+        None => Some(body),
+    }
+}
+
+impl Error for AuctionResultError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            AuctionResultError::Request(e) | AuctionResultError::Timeout(e) => Some(e),
+            AuctionResultError::Decode { source, .. } => Some(source),
+            AuctionResultError::ParseProfile(source) => Some(source),
+            AuctionResultError::ReadProfile(source) => Some(source),
+            AuctionResultError::Retries { source, .. } => Some(source.as_ref()),
+            _ => None,
+        }
+    }
 }
 
 impl From<reqwest::Error> for AuctionResultError {
     fn from(value: reqwest::Error) -> Self {
-        AuctionResultError::Request(value)
+        if value.is_timeout() {
+            AuctionResultError::Timeout(value)
+        } else {
+            AuctionResultError::Request(value)
+        }
+    }
+}
+
+impl From<serde_json::Error> for AuctionResultError {
+    fn from(value: serde_json::Error) -> Self {
+        AuctionResultError::Decode {
+            path: String::from("."),
+            source: value,
+        }
+    }
+}
+
+impl From<serde_path_to_error::Error<serde_json::Error>> for AuctionResultError {
+    fn from(value: serde_path_to_error::Error<serde_json::Error>) -> Self {
+        AuctionResultError::Decode {
+            path: value.path().to_string(),
+            source: value.into_inner(),
+        }
     }
 }
 
@@ -25,12 +211,71 @@ impl From<Box<dyn Any + Send>> for AuctionResultError {
     }
 }
 
-// This enables using `?` on functions that return `Result<_, anyhow::Error>` to turn them into
-// `Result<_, Parse>`. That way you don't need to do that manually.
-// impl<E> From<E> for AuctionResultError
-// where E: Into<reqwest::Error>,
-// {
-//     fn from(err: E) -> Self {
-//         Self(err.into())
-//     }
-// }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::treasury::{decode, Treasuries};
+
+    #[test]
+    fn it_should_name_the_field_that_failed_to_decode() {
+        let body = r#"[{"cusip": "912810SH2", "issueDate": "yesterday"}]"#;
+
+        let error = decode::<Treasuries>(body).unwrap_err();
+
+        assert!(matches!(
+            &error,
+            AuctionResultError::Decode { path, .. } if path == "[0].issueDate"
+        ));
+        assert_eq!(5, error.exit_code());
+        assert!(error.source().is_some());
+    }
+
+    #[test]
+    fn it_should_name_the_malformed_numeric_field() {
+        let body = r#"[{"cusip": "912810SH2", "issueDate": "2019-05-15T00:00:00",
+            "maturityDate": "2049-05-15T00:00:00", "highYield": "2.6a"}]"#;
+
+        let error = decode::<Treasuries>(body).unwrap_err();
+
+        assert_eq!(
+            "Could not decode the field [0].highYield of the response",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn it_should_chain_the_source_of_retries() {
+        let error = AuctionResultError::Retries {
+            attempts: 3,
+            source: Box::new(AuctionResultError::Status {
+                status: StatusCode::BAD_GATEWAY,
+                url: "http://localhost/securities/auctioned".to_owned(),
+                body: String::new(),
+            }),
+        };
+
+        assert_eq!("Giving up after 3 attempts", error.to_string());
+        assert_eq!(
+            "The api answered with status 502 Bad Gateway for http://localhost/securities/auctioned",
+            error.source().unwrap().to_string()
+        );
+        assert_eq!(8, error.exit_code());
+    }
+
+    #[test]
+    fn it_should_show_the_start_of_the_response() {
+        let error = AuctionResultError::Status {
+            status: StatusCode::SERVICE_UNAVAILABLE,
+            url: "http://localhost/securities/auctioned".to_owned(),
+            body: format!("<h1>Maintenance</h1>\n<p>{}</p>", "x".repeat(500)),
+        };
+        let message = error.to_string();
+
+        assert!(message.starts_with(
+            "The api answered with status 503 Service Unavailable for \
+             http://localhost/securities/auctioned: <h1>Maintenance</h1> <p>xxx"
+        ));
+        assert!(message.ends_with("x..."));
+        assert!(message.len() < 400);
+    }
+}
@@ -0,0 +1,530 @@
+//! # The fields of the api.
+//!
+//! Accessors of the fields that are not used by the library itself. A field
+//! is [`None`] if the api leaves it empty or does not return it.
+use chrono::NaiveDateTime;
+use rust_decimal::Decimal;
+
+use super::Treasury;
+
+impl Treasury {
+    /// Return the security type name.
+    pub fn get_security_type_name(&self) -> Option<&str> {
+        self.security_type_name.as_deref()
+    }
+
+    /// Return the ref CPI on issue date.
+    pub fn get_ref_cpi_on_issue_date(&self) -> Option<Decimal> {
+        self.ref_cpi_on_issue_date
+    }
+
+    /// Return the ref CPI on dated date.
+    pub fn get_ref_cpi_on_dated_date(&self) -> Option<Decimal> {
+        self.ref_cpi_on_dated_date
+    }
+
+    /// Return the announcement date.
+    pub fn get_announcement_date(&self) -> Option<NaiveDateTime> {
+        self.announcement_date
+    }
+
+    /// Return the auction date year.
+    pub fn get_auction_date_year(&self) -> Option<u32> {
+        self.auction_date_year
+    }
+
+    /// Return the dated date.
+    pub fn get_dated_date(&self) -> Option<NaiveDateTime> {
+        self.dated_date
+    }
+
+    /// Return the accrued interest per 1000.
+    pub fn get_accrued_interest_per1000(&self) -> Option<Decimal> {
+        self.accrued_interest_per1000
+    }
+
+    /// Return the accrued interest per 100.
+    pub fn get_accrued_interest_per100(&self) -> Option<Decimal> {
+        self.accrued_interest_per100
+    }
+
+    /// Return the adjusted accrued interest per 1000.
+    pub fn get_adjusted_accrued_interest_per1000(&self) -> Option<Decimal> {
+        self.adjusted_accrued_interest_per1000
+    }
+
+    /// Return the adjusted price.
+    pub fn get_adjusted_price(&self) -> Option<Decimal> {
+        self.adjusted_price
+    }
+
+    /// Return the allocation percentage.
+    pub fn get_allocation_percentage(&self) -> Option<Decimal> {
+        self.allocation_percentage
+    }
+
+    /// Return the allocation percentage decimals.
+    pub fn get_allocation_percentage_decimals(&self) -> Option<u32> {
+        self.allocation_percentage_decimals
+    }
+
+    /// Return the announced CUSIP.
+    pub fn get_announced_cusip(&self) -> Option<&str> {
+        self.announced_cusip.as_deref()
+    }
+
+    /// Return the auction format.
+    pub fn get_auction_format(&self) -> Option<&str> {
+        self.auction_format.as_deref()
+    }
+
+    /// Return the average median discount rate.
+    pub fn get_average_median_discount_rate(&self) -> Option<Decimal> {
+        self.average_median_discount_rate
+    }
+
+    /// Return the average median investment rate.
+    pub fn get_average_median_investment_rate(&self) -> Option<Decimal> {
+        self.average_median_investment_rate
+    }
+
+    /// Return the average median price.
+    pub fn get_average_median_price(&self) -> Option<Decimal> {
+        self.average_median_price
+    }
+
+    /// Return the average median discount margin.
+    pub fn get_average_median_discount_margin(&self) -> Option<Decimal> {
+        self.average_median_discount_margin
+    }
+
+    /// Return the average median yield.
+    pub fn get_average_median_yield(&self) -> Option<Decimal> {
+        self.average_median_yield
+    }
+
+    /// Return the back dated flag.
+    pub fn is_back_dated(&self) -> Option<bool> {
+        self.back_dated
+    }
+
+    /// Return the back dated date.
+    pub fn get_back_dated_date(&self) -> Option<NaiveDateTime> {
+        self.back_dated_date
+    }
+
+    /// Return the call date.
+    pub fn get_call_date(&self) -> Option<NaiveDateTime> {
+        self.call_date
+    }
+
+    /// Return the callable flag.
+    pub fn is_callable(&self) -> Option<bool> {
+        self.callable
+    }
+
+    /// Return the called date.
+    pub fn get_called_date(&self) -> Option<NaiveDateTime> {
+        self.called_date
+    }
+
+    /// Return the cash management bill CMB flag.
+    pub fn is_cash_management_bill_cmb(&self) -> Option<bool> {
+        self.cash_management_bill_cmb
+    }
+
+    /// Return the closing time competitive.
+    pub fn get_closing_time_competitive(&self) -> Option<&str> {
+        self.closing_time_competitive.as_deref()
+    }
+
+    /// Return the closing time noncompetitive.
+    pub fn get_closing_time_noncompetitive(&self) -> Option<&str> {
+        self.closing_time_noncompetitive.as_deref()
+    }
+
+    /// Return the competitive bid decimals.
+    pub fn get_competitive_bid_decimals(&self) -> Option<u32> {
+        self.competitive_bid_decimals
+    }
+
+    /// Return the competitive accepted.
+    pub fn get_competitive_accepted(&self) -> Option<Decimal> {
+        self.competitive_accepted
+    }
+
+    /// Return the competitive tendered.
+    pub fn get_competitive_tendered(&self) -> Option<Decimal> {
+        self.competitive_tendered
+    }
+
+    /// Return the competitive tenders accepted flag.
+    pub fn is_competitive_tenders_accepted(&self) -> Option<bool> {
+        self.competitive_tenders_accepted
+    }
+
+    /// Return the corpus CUSIP.
+    pub fn get_corpus_cusip(&self) -> Option<&str> {
+        self.corpus_cusip.as_deref()
+    }
+
+    /// Return the CPI base reference period.
+    pub fn get_cpi_base_reference_period(&self) -> Option<&str> {
+        self.cpi_base_reference_period.as_deref()
+    }
+
+    /// Return the currently outstanding.
+    pub fn get_currently_outstanding(&self) -> Option<Decimal> {
+        self.currently_outstanding
+    }
+
+    /// Return the direct bidder accepted.
+    pub fn get_direct_bidder_accepted(&self) -> Option<Decimal> {
+        self.direct_bidder_accepted
+    }
+
+    /// Return the direct bidder tendered.
+    pub fn get_direct_bidder_tendered(&self) -> Option<Decimal> {
+        self.direct_bidder_tendered
+    }
+
+    /// Return the estimated amount of publicly held maturing securities by type.
+    pub fn get_estimated_amount_of_publicly_held_maturing_securities_by_type(&self) -> Option<Decimal> {
+        self.estimated_amount_of_publicly_held_maturing_securities_by_type
+    }
+
+    /// Return the FIMA included flag.
+    pub fn is_fima_included(&self) -> Option<bool> {
+        self.fima_included
+    }
+
+    /// Return the FIMA noncompetitive accepted.
+    pub fn get_fima_noncompetitive_accepted(&self) -> Option<Decimal> {
+        self.fima_noncompetitive_accepted
+    }
+
+    /// Return the FIMA noncompetitive tendered.
+    pub fn get_fima_noncompetitive_tendered(&self) -> Option<Decimal> {
+        self.fima_noncompetitive_tendered
+    }
+
+    /// Return the first interest period.
+    pub fn get_first_interest_period(&self) -> Option<&str> {
+        self.first_interest_period.as_deref()
+    }
+
+    /// Return the first interest payment date.
+    pub fn get_first_interest_payment_date(&self) -> Option<NaiveDateTime> {
+        self.first_interest_payment_date
+    }
+
+    /// Return the floating rate flag.
+    pub fn is_floating_rate(&self) -> Option<bool> {
+        self.floating_rate
+    }
+
+    /// Return the FRN index determination date.
+    pub fn get_frn_index_determination_date(&self) -> Option<NaiveDateTime> {
+        self.frn_index_determination_date
+    }
+
+    /// Return the FRN index determination rate.
+    pub fn get_frn_index_determination_rate(&self) -> Option<Decimal> {
+        self.frn_index_determination_rate
+    }
+
+    /// Return the high price.
+    pub fn get_high_price(&self) -> Option<Decimal> {
+        self.high_price
+    }
+
+    /// Return the high discount margin.
+    pub fn get_high_discount_margin(&self) -> Option<Decimal> {
+        self.high_discount_margin
+    }
+
+    /// Return the index ratio on issue date.
+    pub fn get_index_ratio_on_issue_date(&self) -> Option<Decimal> {
+        self.index_ratio_on_issue_date
+    }
+
+    /// Return the indirect bidder accepted.
+    pub fn get_indirect_bidder_accepted(&self) -> Option<Decimal> {
+        self.indirect_bidder_accepted
+    }
+
+    /// Return the indirect bidder tendered.
+    pub fn get_indirect_bidder_tendered(&self) -> Option<Decimal> {
+        self.indirect_bidder_tendered
+    }
+
+    /// Return the interest payment frequency.
+    pub fn get_interest_payment_frequency(&self) -> Option<&str> {
+        self.interest_payment_frequency.as_deref()
+    }
+
+    /// Return the low discount rate.
+    pub fn get_low_discount_rate(&self) -> Option<Decimal> {
+        self.low_discount_rate
+    }
+
+    /// Return the low investment rate.
+    pub fn get_low_investment_rate(&self) -> Option<Decimal> {
+        self.low_investment_rate
+    }
+
+    /// Return the low price.
+    pub fn get_low_price(&self) -> Option<Decimal> {
+        self.low_price
+    }
+
+    /// Return the low discount margin.
+    pub fn get_low_discount_margin(&self) -> Option<Decimal> {
+        self.low_discount_margin
+    }
+
+    /// Return the low yield.
+    pub fn get_low_yield(&self) -> Option<Decimal> {
+        self.low_yield
+    }
+
+    /// Return the maturing date.
+    pub fn get_maturing_date(&self) -> Option<NaiveDateTime> {
+        self.maturing_date
+    }
+
+    /// Return the maximum competitive award.
+    pub fn get_maximum_competitive_award(&self) -> Option<Decimal> {
+        self.maximum_competitive_award
+    }
+
+    /// Return the maximum noncompetitive award.
+    pub fn get_maximum_noncompetitive_award(&self) -> Option<Decimal> {
+        self.maximum_noncompetitive_award
+    }
+
+    /// Return the maximum single bid.
+    pub fn get_maximum_single_bid(&self) -> Option<Decimal> {
+        self.maximum_single_bid
+    }
+
+    /// Return the minimum bid amount.
+    pub fn get_minimum_bid_amount(&self) -> Option<Decimal> {
+        self.minimum_bid_amount
+    }
+
+    /// Return the minimum strip amount.
+    pub fn get_minimum_strip_amount(&self) -> Option<Decimal> {
+        self.minimum_strip_amount
+    }
+
+    /// Return the minimum to issue.
+    pub fn get_minimum_to_issue(&self) -> Option<Decimal> {
+        self.minimum_to_issue
+    }
+
+    /// Return the multiples to bid.
+    pub fn get_multiples_to_bid(&self) -> Option<Decimal> {
+        self.multiples_to_bid
+    }
+
+    /// Return the multiples to issue.
+    pub fn get_multiples_to_issue(&self) -> Option<Decimal> {
+        self.multiples_to_issue
+    }
+
+    /// Return the NLP exclusion amount.
+    pub fn get_nlp_exclusion_amount(&self) -> Option<Decimal> {
+        self.nlp_exclusion_amount
+    }
+
+    /// Return the NLP reporting threshold.
+    pub fn get_nlp_reporting_threshold(&self) -> Option<Decimal> {
+        self.nlp_reporting_threshold
+    }
+
+    /// Return the noncompetitive accepted.
+    pub fn get_noncompetitive_accepted(&self) -> Option<Decimal> {
+        self.noncompetitive_accepted
+    }
+
+    /// Return the noncompetitive tenders accepted flag.
+    pub fn is_noncompetitive_tenders_accepted(&self) -> Option<bool> {
+        self.noncompetitive_tenders_accepted
+    }
+
+    /// Return the offering amount.
+    pub fn get_offering_amount(&self) -> Option<Decimal> {
+        self.offering_amount
+    }
+
+    /// Return the original CUSIP.
+    pub fn get_original_cusip(&self) -> Option<&str> {
+        self.original_cusip.as_deref()
+    }
+
+    /// Return the original dated date.
+    pub fn get_original_dated_date(&self) -> Option<NaiveDateTime> {
+        self.original_dated_date
+    }
+
+    /// Return the original issue date.
+    pub fn get_original_issue_date(&self) -> Option<NaiveDateTime> {
+        self.original_issue_date
+    }
+
+    /// Return the PDF filename announcement.
+    pub fn get_pdf_filename_announcement(&self) -> Option<&str> {
+        self.pdf_filename_announcement.as_deref()
+    }
+
+    /// Return the PDF filename competitive results.
+    pub fn get_pdf_filename_competitive_results(&self) -> Option<&str> {
+        self.pdf_filename_competitive_results.as_deref()
+    }
+
+    /// Return the PDF filename noncompetitive results.
+    pub fn get_pdf_filename_noncompetitive_results(&self) -> Option<&str> {
+        self.pdf_filename_noncompetitive_results.as_deref()
+    }
+
+    /// Return the PDF filename special announcement.
+    pub fn get_pdf_filename_special_announcement(&self) -> Option<&str> {
+        self.pdf_filename_special_announcement.as_deref()
+    }
+
+    /// Return the price per 100.
+    pub fn get_price_per100(&self) -> Option<Decimal> {
+        self.price_per100
+    }
+
+    /// Return the primary dealer accepted.
+    pub fn get_primary_dealer_accepted(&self) -> Option<Decimal> {
+        self.primary_dealer_accepted
+    }
+
+    /// Return the primary dealer tendered.
+    pub fn get_primary_dealer_tendered(&self) -> Option<Decimal> {
+        self.primary_dealer_tendered
+    }
+
+    /// Return the security term day month.
+    pub fn get_security_term_day_month(&self) -> Option<&str> {
+        self.security_term_day_month.as_deref()
+    }
+
+    /// Return the security term week year.
+    pub fn get_security_term_week_year(&self) -> Option<&str> {
+        self.security_term_week_year.as_deref()
+    }
+
+    /// Return the series.
+    pub fn get_series(&self) -> Option<&str> {
+        self.series.as_deref()
+    }
+
+    /// Return the SOMA accepted.
+    pub fn get_soma_accepted(&self) -> Option<Decimal> {
+        self.soma_accepted
+    }
+
+    /// Return the SOMA holdings.
+    pub fn get_soma_holdings(&self) -> Option<Decimal> {
+        self.soma_holdings
+    }
+
+    /// Return the SOMA included flag.
+    pub fn is_soma_included(&self) -> Option<bool> {
+        self.soma_included
+    }
+
+    /// Return the SOMA tendered.
+    pub fn get_soma_tendered(&self) -> Option<Decimal> {
+        self.soma_tendered
+    }
+
+    /// Return the spread.
+    pub fn get_spread(&self) -> Option<Decimal> {
+        self.spread
+    }
+
+    /// Return the standard interest payment per 1000.
+    pub fn get_standard_interest_payment_per1000(&self) -> Option<Decimal> {
+        self.standard_interest_payment_per1000
+    }
+
+    /// Return the strippable flag.
+    pub fn is_strippable(&self) -> Option<bool> {
+        self.strippable
+    }
+
+    /// Return the TIIN conversion factor per 1000.
+    pub fn get_tiin_conversion_factor_per1000(&self) -> Option<Decimal> {
+        self.tiin_conversion_factor_per1000
+    }
+
+    /// Return the tips flag.
+    pub fn is_tips(&self) -> Option<bool> {
+        self.tips
+    }
+
+    /// Return the total accepted.
+    pub fn get_total_accepted(&self) -> Option<Decimal> {
+        self.total_accepted
+    }
+
+    /// Return the total tendered.
+    pub fn get_total_tendered(&self) -> Option<Decimal> {
+        self.total_tendered
+    }
+
+    /// Return the treasury retail accepted.
+    pub fn get_treasury_retail_accepted(&self) -> Option<Decimal> {
+        self.treasury_retail_accepted
+    }
+
+    /// Return the treasury retail tenders accepted flag.
+    pub fn is_treasury_retail_tenders_accepted(&self) -> Option<bool> {
+        self.treasury_retail_tenders_accepted
+    }
+
+    /// Return the unadjusted accrued interest per 1000.
+    pub fn get_unadjusted_accrued_interest_per1000(&self) -> Option<Decimal> {
+        self.unadjusted_accrued_interest_per1000
+    }
+
+    /// Return the unadjusted price.
+    pub fn get_unadjusted_price(&self) -> Option<Decimal> {
+        self.unadjusted_price
+    }
+
+    /// Return the updated timestamp.
+    pub fn get_updated_timestamp(&self) -> Option<NaiveDateTime> {
+        self.updated_timestamp
+    }
+
+    /// Return the XML filename announcement.
+    pub fn get_xml_filename_announcement(&self) -> Option<&str> {
+        self.xml_filename_announcement.as_deref()
+    }
+
+    /// Return the XML filename competitive results.
+    pub fn get_xml_filename_competitive_results(&self) -> Option<&str> {
+        self.xml_filename_competitive_results.as_deref()
+    }
+
+    /// Return the XML filename special announcement.
+    pub fn get_xml_filename_special_announcement(&self) -> Option<&str> {
+        self.xml_filename_special_announcement.as_deref()
+    }
+
+    /// Return the TINT CUSIP 1.
+    pub fn get_tint_cusip1(&self) -> Option<&str> {
+        self.tint_cusip1.as_deref()
+    }
+
+    /// Return the TINT CUSIP 2.
+    pub fn get_tint_cusip2(&self) -> Option<&str> {
+        self.tint_cusip2.as_deref()
+    }
+}
@@ -1,31 +1,108 @@
 //! # This module retrieves the treasury data from the given url.
 //!
 
-use super::error::AuctionResultError;
-use reqwest::blocking::{get, Response};
+use serde::de::DeserializeOwned;
+
+use super::{cache::CacheMode, error::AuctionResultError, retry::give_up, AuctionResult};
+use crate::client::TreasuryClient;
+#[cfg(feature = "blocking")]
 use std::thread;
 
-/// Naive version of non blocking request.
-pub fn load(url: impl Into<String>) -> Result<Response, AuctionResultError> {
+/// Load the body of the given url through the transport of the client, served
+/// through the cache of the client if there is one. Transient errors are retried as
+/// described by the retry policy of the client.
+#[cfg(feature = "blocking")]
+pub fn load(client: &TreasuryClient, url: impl Into<String>) -> AuctionResult<String> {
     let url = url.into();
-    let handle = thread::spawn(move || get(url));
 
+    if let Some(body) = lookup(client, &url)? {
+        return Ok(body);
+    }
+
+    let mut attempt = 1;
+
+    let body = loop {
+        match fetch(client, &url) {
+            Ok(body) => break body,
+            Err(e) if client.retry().should_retry(attempt, &e) => {
+                std::thread::sleep(client.retry().delay(attempt));
+                attempt += 1;
+            }
+            Err(e) => return Err(give_up(attempt, e)),
+        }
+    };
+
+    store(client, &url, &body);
+
+    Ok(body)
+}
+
+/// Load the body of the given url without blocking the current thread.
+pub async fn load_async(client: &TreasuryClient, url: impl Into<String>) -> AuctionResult<String> {
+    let url = url.into();
+
+    if let Some(body) = lookup(client, &url)? {
+        return Ok(body);
+    }
+
+    let mut attempt = 1;
+
+    let body = loop {
+        match client.transport().fetch_async(&url).await {
+            Ok(body) => break body,
+            Err(e) if client.retry().should_retry(attempt, &e) => {
+                tokio::time::sleep(client.retry().delay(attempt)).await;
+                attempt += 1;
+            }
+            Err(e) => return Err(give_up(attempt, e)),
+        }
+    };
+
+    store(client, &url, &body);
+
+    Ok(body)
+}
+
+/// Return the body of the url fetched by the transport of the client. The
+/// transport runs on its own thread, so that it can be used from within an
+/// async runtime.
+#[cfg(feature = "blocking")]
+fn fetch(client: &TreasuryClient, url: &str) -> AuctionResult<String> {
     // Joining the thread failed.
-    let thread_result = handle.join()?;
+    thread::scope(|scope| scope.spawn(|| client.transport().fetch(url)).join())?
+}
 
-    // // Joining the thread failed.
-    // let Ok(tr) = thread_result else {
-    //     return Err(AuctionResultError::RequestErrorDyn(thread_result.unwrap_err()));
-    // };
+/// Decode the body of a response. A failure names the path of the field that
+/// could not be decoded.
+pub fn decode<T: DeserializeOwned>(body: &str) -> AuctionResult<T> {
+    let deserializer = &mut serde_json::Deserializer::from_str(body);
 
-    // Invalid server response.
-    let response = thread_result?;
+    Ok(serde_path_to_error::deserialize(deserializer)?)
+}
+
+/// Return the cached body of the url, if the cache can serve it.
+fn lookup(client: &TreasuryClient, url: &str) -> AuctionResult<Option<String>> {
+    let Some(cache) = client.cache() else {
+        return Ok(None);
+    };
+
+    if cache.mode() != CacheMode::Refresh {
+        if let Some(body) = cache.read(url) {
+            return Ok(Some(body));
+        }
+    }
 
-    // let Ok(response) = thread_result else {
-    //     return Err(AuctionResultError::RequestError(thread_result.unwrap_err()));
-    // };
+    if cache.mode() == CacheMode::Offline {
+        return Err(AuctionResultError::NotCached(url.to_owned()));
+    }
+
+    Ok(None)
+}
 
-    response
-        .error_for_status()
-        .map_err(AuctionResultError::Request)
+/// Store the loaded body in the cache, if there is one.
+fn store(client: &TreasuryClient, url: &str, body: &str) {
+    if let Some(cache) = client.cache() {
+        // A cache that cannot be written must not fail the request.
+        let _ = cache.write(url, body);
+    }
 }
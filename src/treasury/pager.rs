@@ -0,0 +1,182 @@
+//! # Pagination of the api.
+//!
+//! The api returns at most one page of records per request. The pager builds
+//! the urls of the consecutive pages by the `pagesize` and `pagenum`
+//! parameters and collects the records until a limit or a date bound is
+//! reached or the api runs out of records.
+use chrono::NaiveDate;
+
+use super::{Treasuries, Treasury};
+
+/// The number of records requested per page.
+pub const PAGE_SIZE: usize = 250;
+
+/// The number of the first page.
+const FIRST_PAGE: usize = 0;
+
+/// The maximum number of pages loaded by a single request of the caller.
+const MAX_PAGES: usize = 100;
+
+/// Collects the records of consecutive pages.
+#[derive(Debug, Default)]
+pub(crate) struct Pager {
+    limit: Option<usize>,
+    since: Option<NaiveDate>,
+    page: usize,
+    done: bool,
+    first_cusip: Option<String>,
+    treasuries: Treasuries,
+}
+
+impl Pager {
+    /// Create a pager that collects up to [`limit`] records that were auctioned
+    /// on or after [`since`]. Without any bound only a single page is loaded.
+    pub fn new(limit: Option<usize>, since: Option<NaiveDate>) -> Self {
+        Self {
+            limit,
+            since,
+            page: FIRST_PAGE,
+            ..Self::default()
+        }
+    }
+
+    /// Return if the pager follows the pages of the api.
+    fn is_paged(&self) -> bool {
+        self.limit.is_some() || self.since.is_some()
+    }
+
+    /// Return the url of the next page, if there is one.
+    pub fn next_url(&self, url: &str) -> Option<String> {
+        if self.done {
+            return None;
+        }
+
+        if !self.is_paged() {
+            return Some(url.to_owned());
+        }
+
+        let separator = if url.contains('?') { '&' } else { '?' };
+
+        Some(format!(
+            "{}{}pagesize={}&pagenum={}",
+            url, separator, PAGE_SIZE, self.page
+        ))
+    }
+
+    /// Collect the records of the loaded page, which pass the filter.
+    pub fn push(&mut self, page: Treasuries, keep: impl Fn(&Treasury) -> bool) {
+        let count = page.len();
+        let first_cusip = page.first().map(|t| t.cusip().to_owned());
+
+        // An api that ignores the page number returns the same page again.
+        if self.page > FIRST_PAGE && first_cusip == self.first_cusip {
+            self.done = true;
+            return;
+        }
+        self.first_cusip = first_cusip;
+
+        let mut passed_since = false;
+
+        for treasury in page {
+            if self.is_before_since(&treasury) {
+                passed_since = true;
+                continue;
+            }
+
+            if keep(&treasury) {
+                self.treasuries.push(treasury);
+            }
+        }
+
+        let reached_limit = self
+            .limit
+            .is_some_and(|limit| self.treasuries.len() >= limit);
+
+        if let Some(limit) = self.limit {
+            self.treasuries.truncate(limit);
+        }
+
+        self.page += 1;
+        self.done = !self.is_paged()
+            || reached_limit
+            || passed_since
+            || count < PAGE_SIZE
+            || self.page - FIRST_PAGE >= MAX_PAGES;
+    }
+
+    /// Return the collected records.
+    pub fn finish(self) -> Treasuries {
+        self.treasuries
+    }
+
+    /// Return if the treasury was auctioned before the date bound.
+    fn is_before_since(&self, treasury: &Treasury) -> bool {
+        match (self.since, treasury.get_auction_date()) {
+            (Some(since), Some(auction_date)) => auction_date.date() < since,
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::fixture::api_many_items;
+
+    fn page() -> Treasuries {
+        serde_json::from_str(api_many_items()).unwrap()
+    }
+
+    #[test]
+    fn it_should_load_a_single_page_without_bounds() {
+        let mut pager = Pager::new(None, None);
+
+        assert_eq!(Some("/a?days=0".to_owned()), pager.next_url("/a?days=0"));
+        pager.push(page(), |_| true);
+        assert!(pager.next_url("/a?days=0").is_none());
+        assert_eq!(PAGE_SIZE, pager.finish().len());
+    }
+
+    #[test]
+    fn it_should_follow_the_pages_up_to_the_limit() {
+        let mut pager = Pager::new(Some(300), None);
+
+        assert_eq!(
+            Some("/a?type=Bond&pagesize=250&pagenum=0".to_owned()),
+            pager.next_url("/a?type=Bond")
+        );
+        pager.push(page(), |_| true);
+        assert_eq!(
+            Some("/a?type=Bond&pagesize=250&pagenum=1".to_owned()),
+            pager.next_url("/a?type=Bond")
+        );
+        pager.push(page().into_iter().rev().collect(), |_| true);
+        assert!(pager.next_url("/a?type=Bond").is_none());
+        assert_eq!(300, pager.finish().len());
+    }
+
+    #[test]
+    fn it_should_stop_if_the_page_number_is_ignored() {
+        let mut pager = Pager::new(Some(1000), None);
+
+        pager.push(page(), |_| true);
+        pager.push(page(), |_| true);
+        assert!(pager.next_url("/a").is_none());
+        assert_eq!(PAGE_SIZE, pager.finish().len());
+    }
+
+    #[test]
+    fn it_should_stop_at_the_date_bound() {
+        let since = NaiveDate::from_ymd_opt(2020, 1, 1).unwrap();
+        let mut pager = Pager::new(None, Some(since));
+
+        pager.push(page(), |_| true);
+        assert!(pager.next_url("/a").is_none());
+
+        let treasuries = pager.finish();
+        assert!(!treasuries.is_empty());
+        assert!(treasuries
+            .iter()
+            .all(|t| t.get_auction_date().unwrap().date() >= since));
+    }
+}
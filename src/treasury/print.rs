@@ -2,15 +2,22 @@
 //!
 extern crate prettytable;
 
-use crate::{quality::AuctionQuality, SecurityType};
+use crate::{
+    chain::ChainLink,
+    quality::{AuctionQuality, Statistics},
+    SecurityType,
+};
 
 use super::{Treasuries, Treasury};
+use rust_decimal::{Decimal, RoundingStrategy};
+use serde::Serialize;
 use prettytable::{
     format::{self, Alignment},
     row, Cell, Row, Table,
 };
 
 // Security Term:   3-Year
+// Bucket:          3-Year
 // CUSIP:           912828YF1
 // Reopening:       No
 // Security Type:   Note
@@ -22,79 +29,181 @@ use prettytable::{
 // Interest Rate:   1.500%
 
 pub fn security_print(treasuries: &Treasuries) {
+    if treasuries.is_empty() {
+        println!("No treasuries matching the criteria available!");
+        return;
+    }
+
+    security_table(treasuries).printstd()
+}
+
+/// Return the table of the treasuries, one per row.
+fn security_table(treasuries: &Treasuries) -> Table {
     // let mut f = numfmt::Formatter::default();
     let mut table = Table::new();
     let datefmt = Treasury::get_default_date_fmt();
 
-    if treasuries.is_empty() {
-        println!("No treasuries matching the criteria available!");
-        return;
+    // A list of TIPS gets the columns of the inflation adjustment, a list of
+    // FRNs the columns of the discount margin.
+    let tips = treasuries
+        .iter()
+        .all(|t| t.get_security_type() == SecurityType::Tips);
+    let frns = treasuries
+        .iter()
+        .all(|t| t.get_security_type() == SecurityType::Frn);
+
+    // The tail is shown if a when-issued yield was supplied.
+    let when_issued = treasuries
+        .iter()
+        .any(|t| t.get_when_issued_yield().is_some());
+
+    let mut fields = if tips || frns {
+        treasuries[0].get_fields()
+    } else {
+        Treasury::new().get_fields()
+    };
+
+    // A mixed list shows the discount margin and the spread of its FRNs in
+    // the columns of the yield and the interest rate.
+    if !frns
+        && treasuries
+            .iter()
+            .any(|t| t.get_security_type() == SecurityType::Frn)
+    {
+        for field in fields.iter_mut() {
+            match *field {
+                "High Yield" => *field = "High Yield / Margin",
+                "Interest Rate" => *field = "Interest Rate / Spread",
+                _ => (),
+            }
+        }
     }
+
+    if when_issued {
+        fields.push("WI Yield");
+        fields.push("Tail");
+    }
+
     // table.set_format(*format::consts::FORMAT_CLEAN);
-    table.add_row(Row::from(Treasury::new().get_fields()));
+    table.add_row(Row::from(fields));
 
     for treasury in treasuries {
-        table.add_row(Row::new(vec![
+        let mut row = Row::new(vec![
             Cell::new(&treasury.security_term),
-            Cell::new(&treasury.cusip),
+            Cell::new(&format_bucket(treasury)),
+            Cell::new(treasury.cusip()),
             Cell::new_align(
                 if treasury.reopening { "Yes" } else { "No" },
                 Alignment::CENTER,
             ),
-            Cell::new(&treasury.security_type.to_string()),
+            Cell::new(&treasury.get_security_type().to_string()),
             Cell::new(&format!("{}", treasury.issue_date.format(datefmt))),
             Cell::new(&format!("{}", treasury.maturity_date.format(datefmt))),
             Cell::new_align(
-                &format!("{:.2}", treasury.bid_to_cover_ratio),
+                &format_optional(treasury.bid_to_cover_ratio, 2),
                 Alignment::RIGHT,
             ),
             Cell::new_align(
-                &format!(
-                    "{:.2}%",
-                    treasury.get_percentage_debt_purchased_by_dealers()
-                ),
+                &format_percentage(treasury.get_percentage_debt_purchased_by_dealers(), 2),
+                Alignment::RIGHT,
+            ),
+            Cell::new_align(
+                &format_percentage(treasury.get_percentage_debt_purchased_by_directs(), 2),
                 Alignment::RIGHT,
             ),
             Cell::new_align(
-                &format!(
-                    "{:.2}%",
-                    treasury.get_percentage_debt_purchased_by_directs()
+                &format_percentage(treasury.get_percentage_debt_purchased_by_indirects(), 2),
+                Alignment::RIGHT,
+            ),
+            Cell::new_align(
+                &format_cover(
+                    treasury.get_dealers_cover_ratio(),
+                    treasury.get_percentage_tendered_by_dealers(),
                 ),
                 Alignment::RIGHT,
             ),
             Cell::new_align(
-                &format!(
-                    "{:.2}%",
-                    treasury.get_percentage_debt_purchased_by_indirects()
+                &format_cover(
+                    treasury.get_directs_cover_ratio(),
+                    treasury.get_percentage_tendered_by_directs(),
                 ),
                 Alignment::RIGHT,
             ),
             Cell::new_align(
-                &format!(
-                    "{:.3}%",
+                &format_cover(
+                    treasury.get_indirects_cover_ratio(),
+                    treasury.get_percentage_tendered_by_indirects(),
+                ),
+                Alignment::RIGHT,
+            ),
+        ]);
+
+        if treasury.security_type == SecurityType::Frn {
+            row.add_cell(Cell::new_align(
+                &format_percentage(treasury.get_high_discount_margin(), 3),
+                Alignment::RIGHT,
+            ));
+            row.add_cell(Cell::new_align(
+                &format_percentage(treasury.get_spread(), 3),
+                Alignment::RIGHT,
+            ));
+        } else {
+            row.add_cell(Cell::new_align(
+                &format_percentage(
                     if treasury.security_type == SecurityType::Bill {
                         treasury.high_discount_rate
                     } else {
                         treasury.high_yield
-                    }
+                    },
+                    3,
                 ),
                 Alignment::RIGHT,
-            ),
-            Cell::new_align(
-                &format!(
-                    "{:.3}%",
+            ));
+            row.add_cell(Cell::new_align(
+                &format_percentage(
                     if treasury.security_type == SecurityType::Bill {
                         treasury.high_investment_rate
                     } else {
                         treasury.interest_rate
-                    }
+                    },
+                    3,
                 ),
                 Alignment::RIGHT,
-            ),
-        ]));
+            ));
+        }
+
+        if tips {
+            row.add_cell(Cell::new_align(
+                &format_optional(treasury.get_ref_cpi_on_issue_date(), 5),
+                Alignment::RIGHT,
+            ));
+            row.add_cell(Cell::new_align(
+                &format_optional(treasury.get_index_ratio_on_issue_date(), 5),
+                Alignment::RIGHT,
+            ));
+        } else if frns {
+            row.add_cell(Cell::new_align(
+                &format_percentage(treasury.get_low_discount_margin(), 3),
+                Alignment::RIGHT,
+            ));
+            row.add_cell(Cell::new_align(
+                &format_percentage(treasury.get_frn_index_determination_rate(), 3),
+                Alignment::RIGHT,
+            ));
+        }
+
+        if when_issued {
+            row.add_cell(Cell::new_align(
+                &format_percentage(treasury.get_when_issued_yield(), 3),
+                Alignment::RIGHT,
+            ));
+            row.add_cell(Cell::new_align(&format_tail(treasury.get_tail()), Alignment::RIGHT));
+        }
+
+        table.add_row(row);
     }
 
-    table.printstd()
+    table
 }
 
 /// Print treasuries in a vertical output format.
@@ -106,12 +215,13 @@ pub fn security_vprint(treasuries: &Treasuries) {
 
     for treasury in treasuries {
         table.add_row(row!["Security Term:", &treasury.security_term]);
-        table.add_row(row!["CUSIP", &treasury.cusip]);
+        table.add_row(row!["Bucket:", format_bucket(treasury)]);
+        table.add_row(row!["CUSIP", treasury.cusip()]);
         table.add_row(row![
             "Reopening:",
             if treasury.reopening { "Yes" } else { "No" }
         ]);
-        table.add_row(row!["Security Type:", &treasury.security_type]);
+        table.add_row(row!["Security Type:", &treasury.get_security_type()]);
         table.add_row(row!["Issue Date:", treasury.issue_date.format(datefmt)]);
         table.add_row(row![
             "Maturity Date:",
@@ -119,54 +229,209 @@ pub fn security_vprint(treasuries: &Treasuries) {
         ]);
         table.add_row(row![
             "Bid To Cover:",
-            format!("{:.2}", treasury.bid_to_cover_ratio)
+            format_optional(treasury.bid_to_cover_ratio, 2)
         ]);
         table.add_row(row![
             "Dealers %:",
-            format!(
-                "{:.2}%",
-                treasury.get_percentage_debt_purchased_by_dealers()
-            )
+            format_percentage(treasury.get_percentage_debt_purchased_by_dealers(), 2)
         ]);
         table.add_row(row![
             "Directs %:",
-            format!(
-                "{:.2}%",
-                treasury.get_percentage_debt_purchased_by_directs()
-            )
+            format_percentage(treasury.get_percentage_debt_purchased_by_directs(), 2)
         ]);
         table.add_row(row![
             "Indirects %:",
-            format!(
-                "{:.2}%",
-                treasury.get_percentage_debt_purchased_by_indirects()
+            format_percentage(treasury.get_percentage_debt_purchased_by_indirects(), 2)
+        ]);
+        table.add_row(row![
+            "Dealers Cover:",
+            format_cover(
+                treasury.get_dealers_cover_ratio(),
+                treasury.get_percentage_tendered_by_dealers()
+            )
+        ]);
+        table.add_row(row![
+            "Directs Cover:",
+            format_cover(
+                treasury.get_directs_cover_ratio(),
+                treasury.get_percentage_tendered_by_directs()
+            )
+        ]);
+        table.add_row(row![
+            "Indirects Cover:",
+            format_cover(
+                treasury.get_indirects_cover_ratio(),
+                treasury.get_percentage_tendered_by_indirects()
             )
         ]);
 
-        // TODO: Find out how FRN rates are shown.
         if treasury.security_type == SecurityType::Bill || treasury.security_type == SecurityType::Cmb {
             table.add_row(row![
                 "High Rate:",
-                &format!("{:.3}%", treasury.high_discount_rate)
+                &format_percentage(treasury.high_discount_rate, 3)
             ]);
             table.add_row(row![
                 "Investment Rate:",
-                &format!("{:.3}%", treasury.high_investment_rate)
+                &format_percentage(treasury.high_investment_rate, 3)
             ]);
+        } else if treasury.security_type == SecurityType::Frn {
+            add_frn_rows(&mut table, treasury);
+        } else if treasury.get_security_type() == SecurityType::Tips {
+            add_tips_rows(&mut table, treasury);
         } else {
-            table.add_row(row!["High Yield:", &format!("{:.3}%", treasury.high_yield)]);
+            table.add_row(row!["High Yield:", &format_percentage(treasury.high_yield, 3)]);
             table.add_row(row![
                 "Interest Rate:",
-                &format!("{:.3}%", treasury.interest_rate)
+                &format_percentage(treasury.interest_rate, 3)
             ]);
         }
 
+        add_when_issued_rows(&mut table, treasury);
         table.add_row(Row::empty());
     }
 
     table.printstd()
 }
 
+/// Add the when-issued yield and the tail to the vertical output, if a
+/// when-issued yield was supplied.
+fn add_when_issued_rows(table: &mut Table, treasury: &Treasury) {
+    if treasury.get_when_issued_yield().is_some() {
+        table.add_row(row![
+            "WI Yield:",
+            format_percentage(treasury.get_when_issued_yield(), 3)
+        ]);
+        table.add_row(row!["Tail:", format_tail(treasury.get_tail())]);
+    }
+}
+
+/// Add the real yield and the inflation adjustment of a TIPS to the vertical output.
+fn add_tips_rows(table: &mut Table, treasury: &Treasury) {
+    table.add_row(row!["Real Yield:", &format_percentage(treasury.high_yield, 3)]);
+    table.add_row(row!["Interest Rate:", &format_percentage(treasury.interest_rate, 3)]);
+    table.add_row(row![
+        "Ref CPI:",
+        format_optional(treasury.get_ref_cpi_on_issue_date(), 5)
+    ]);
+    table.add_row(row![
+        "Index Ratio:",
+        format_optional(treasury.get_index_ratio_on_issue_date(), 5)
+    ]);
+}
+
+/// Add the discount margins, the spread and the index rate of a FRN to the vertical output.
+fn add_frn_rows(table: &mut Table, treasury: &Treasury) {
+    table.add_row(row![
+        "High Discount Margin:",
+        format_percentage(treasury.get_high_discount_margin(), 3)
+    ]);
+    table.add_row(row![
+        "Low Discount Margin:",
+        format_percentage(treasury.get_low_discount_margin(), 3)
+    ]);
+    table.add_row(row!["Spread:", format_percentage(treasury.get_spread(), 3)]);
+    table.add_row(row![
+        "Index Rate:",
+        format_percentage(treasury.get_frn_index_determination_rate(), 3)
+    ]);
+}
+
+/// Format a decimal rounded half away from zero to the given precision, the
+/// way the Treasury press releases round.
+fn format_decimal(value: Decimal, precision: usize) -> String {
+    let rounded =
+        value.round_dp_with_strategy(precision as u32, RoundingStrategy::MidpointAwayFromZero);
+
+    format!("{:.*}", precision, rounded)
+}
+
+/// Format a rate or a percentage with the given precision.
+fn format_rate(value: Decimal, precision: usize) -> String {
+    format!("{}%", format_decimal(value, precision))
+}
+
+/// Format an optional percentage with the given precision.
+fn format_percentage(value: Option<Decimal>, precision: usize) -> String {
+    value.map_or("n/a".to_owned(), |v| format_rate(v, precision))
+}
+
+/// Format an optional value with the given precision.
+fn format_optional(value: Option<Decimal>, precision: usize) -> String {
+    value.map_or("n/a".to_owned(), |v| format_decimal(v, precision))
+}
+
+/// Format the benchmark bucket of the remaining term of the treasury.
+fn format_bucket(treasury: &Treasury) -> String {
+    treasury.get_bucket().map_or("n/a".to_owned(), |bucket| bucket.to_string())
+}
+
+/// Format a change to the prior auction with its sign.
+fn format_change(change: Option<Decimal>, precision: usize) -> String {
+    change.map_or("n/a".to_owned(), |c| {
+        let sign = if c.is_sign_positive() { "+" } else { "" };
+        format!("{}{}", sign, format_decimal(c, precision))
+    })
+}
+
+/// Format the z-score and the percentile rank of a component.
+fn format_statistics(statistics: Statistics) -> String {
+    let z_score = statistics
+        .z_score()
+        .map_or("n/a".to_owned(), |z| format!("{:+.2}", z));
+    let percentile = statistics
+        .percentile()
+        .map_or("n/a".to_owned(), |p| format!("{:.0}", p));
+
+    format!("z {}, percentile {}", z_score, percentile)
+}
+
+/// Format the tail in basis points, a negative tail is a stop-through.
+fn format_tail(tail: Option<Decimal>) -> String {
+    match tail {
+        Some(tail) if tail.is_sign_negative() && !tail.is_zero() => {
+            format!("{} bp (stop-through)", format_decimal(tail, 1))
+        }
+        Some(tail) => format!("{} bp (tail)", format_decimal(tail, 1)),
+        None => "n/a".to_owned(),
+    }
+}
+
+/// Format the cover ratio of a bidder class with its share of the tenders.
+fn format_cover(cover_ratio: Option<Decimal>, tendered: Option<Decimal>) -> String {
+    let cover_ratio = cover_ratio.map_or("n/a".to_owned(), |c| format_decimal(c, 2));
+    let tendered = tendered.map_or("n/a".to_owned(), |t| format_rate(t, 2));
+
+    format!("{} ({})", cover_ratio, tendered)
+}
+
+/// Print treasuries as json, in the format they can be loaded again.
+pub fn security_json_print(treasuries: &Treasuries) {
+    json_print(treasuries)
+}
+
+/// Print the reopening chain of a cusip as json.
+pub fn chain_json_print(links: &[ChainLink]) {
+    json_print(&links)
+}
+
+/// Print the qualities of many auctions as json.
+pub fn auction_qualities_json_print(qualities: &[AuctionQuality]) {
+    json_print(&qualities)
+}
+
+/// Print the quality of an auction as json.
+pub fn auction_quality_json_print(quality: &AuctionQuality) {
+    json_print(quality)
+}
+
+/// Print the value as pretty json.
+fn json_print(value: &impl Serialize) {
+    match serde_json::to_string_pretty(value) {
+        Ok(json) => println!("{}", json),
+        Err(e) => eprintln!("Could not encode the result: {}", e),
+    }
+}
+
 /// Print auction quotes for a given treasury.
 pub fn auction_quality_print(quality: &AuctionQuality) {
     let mut table = Table::new();
@@ -177,6 +442,7 @@ pub fn auction_quality_print(quality: &AuctionQuality) {
 
     table.add_empty_row();
     table.add_row(row!["Number of auctions used to calculate the quality:", quality.get_number_of_lookback_auctions()]);
+    table.add_row(row!["Lookback:", quality.get_lookback()]);
     table.add_row(row!["Security Term:", treasury.get_term()]);
     table.add_row(row!["CUSIP", treasury.cusip()]);
 
@@ -185,7 +451,7 @@ pub fn auction_quality_print(quality: &AuctionQuality) {
         if treasury.is_reopening() { "Yes" } else { "No" }
     ]);
 
-    table.add_row(row!["Security Type:", treasury.security_type]);
+    table.add_row(row!["Security Type:", treasury.get_security_type()]);
     table.add_row(row!["Issue Date:", treasury.issue_date.format(datefmt)]);
     table.add_row(row!["Maturity Date", treasury.issue_date.format(datefmt)]);
     table.add_row(row!["Maturity Date:", treasury.maturity_date.format(datefmt)]);
@@ -193,52 +459,357 @@ pub fn auction_quality_print(quality: &AuctionQuality) {
     table.add_row(row![
         "Bid To Cover:",
         format!(
-            "{:.2} ({:.2})",
-            treasury.get_bid_to_cover_ratio(),
-            quality.get_bid_to_cover_ratio(),
-        )
+            "{} ({})",
+            format_optional(treasury.get_bid_to_cover_ratio(), 2),
+            format_optional(quality.get_bid_to_cover_ratio(), 2),
+        ),
+        format_statistics(quality.get_bid_to_cover_statistics())
     ]);
 
     table.add_row(row![
         "Dealers %",
         format!(
-            "{:.2}% ({:.2}%)",
-            treasury.get_percentage_debt_purchased_by_dealers(),
-            quality.get_percentage_debt_purchased_by_dealers(),
-        )
+            "{} ({})",
+            format_percentage(treasury.get_percentage_debt_purchased_by_dealers(), 2),
+            format_percentage(quality.get_percentage_debt_purchased_by_dealers(), 2),
+        ),
+        format_statistics(quality.get_dealers_statistics())
     ]);
 
     table.add_row(row![
         "Indirects %",
         format!(
-            "{:.2}% ({:.2}%)",
-            treasury.get_percentage_debt_purchased_by_indirects(),
-            quality.get_percentage_debt_purchased_by_indirects()
-        )
+            "{} ({})",
+            format_percentage(treasury.get_percentage_debt_purchased_by_indirects(), 2),
+            format_percentage(quality.get_percentage_debt_purchased_by_indirects(), 2),
+        ),
+        format_statistics(quality.get_indirects_statistics())
     ]);
 
     table.add_row(row![
         "Directs %",
         format!(
-            "{:.2}% ({:.2}%)",
-            treasury.get_percentage_debt_purchased_by_directs(),
-            quality.get_percentage_debt_purchased_by_directs(),
-        )
+            "{} ({})",
+            format_percentage(treasury.get_percentage_debt_purchased_by_directs(), 2),
+            format_percentage(quality.get_percentage_debt_purchased_by_directs(), 2),
+        ),
+        format_statistics(quality.get_directs_statistics())
     ]);
 
     if treasury.security_type == SecurityType::Bill {
-        table.add_row(row!["High Rate:", &format!("{:.3}%", treasury.high_discount_rate)]);
+        table.add_row(row![
+            "High Rate:",
+            &format!(
+                "{} ({})",
+                format_percentage(treasury.high_discount_rate, 3),
+                format_percentage(quality.get_auction_rate_mean(), 3),
+            )
+        ]);
         table.add_row(row![
             "Investment Rate:",
-            &format!("{:.3}%", treasury.high_investment_rate)
+            &format_percentage(treasury.high_investment_rate, 3)
         ]);
+    } else if treasury.security_type == SecurityType::Frn {
+        table.add_row(row![
+            "High Discount Margin:",
+            format!(
+                "{} ({})",
+                format_percentage(treasury.get_high_discount_margin(), 3),
+                format_percentage(quality.get_auction_rate_mean(), 3)
+            ),
+            format_statistics(quality.get_auction_rate_statistics())
+        ]);
+        table.add_row(row!["Spread:", format_percentage(treasury.get_spread(), 3)]);
+    } else if treasury.get_security_type() == SecurityType::Tips {
+        add_tips_rows(&mut table, &treasury);
     } else {
-        table.add_row(row!["High Yield:", &format!("{:.3}%", treasury.high_yield)]);
-        table.add_row(row!["Interest Rate:", &format!("{:.3}%", treasury.interest_rate)]);
+        table.add_row(row![
+            "High Yield:",
+            &format!(
+                "{} ({})",
+                format_percentage(treasury.high_yield, 3),
+                format_percentage(quality.get_auction_rate_mean(), 3)
+            )
+        ]);
+        table.add_row(row!["Interest Rate:", &format_percentage(treasury.interest_rate, 3)]);
     }
 
+    add_when_issued_rows(&mut table, &treasury);
     table.add_row(row!["Quality:", &format!("{:.3}", quality.get())]);
+    table.add_row(row![
+        "Score:",
+        quality
+            .get_score()
+            .map_or("n/a".to_owned(), |score| format!("{:.1} / 100", score))
+    ]);
+    table.add_row(row!["Profile:", quality.get_profile()]);
     table.add_row(Row::empty());
 
     table.printstd();
-}
\ No newline at end of file
+}
+
+/// Print the auctions of a reopening chain, with the amount issued so far and
+/// the changes to the auction before.
+pub fn chain_print(links: &[ChainLink]) {
+    let mut table = Table::new();
+    let datefmt = Treasury::get_default_date_fmt();
+
+    // The tail is shown if a when-issued yield was supplied.
+    let when_issued = links
+        .iter()
+        .any(|link| link.get_treasury().get_when_issued_yield().is_some());
+
+    let mut header = row![
+        "Auction Date",
+        "Security Term",
+        "CUSIP",
+        "Reopening",
+        "Issue Date",
+        "Size",
+        "Cumulative",
+        "Bid To Cover",
+        "Change",
+        "Rate",
+        "Change (bp)",
+    ];
+    if when_issued {
+        header.add_cell(Cell::new("WI Yield"));
+        header.add_cell(Cell::new("Tail"));
+    }
+    table.add_row(header);
+
+    for link in links {
+        let treasury = link.get_treasury();
+
+        let mut row = Row::new(vec![
+            Cell::new(&format_auction_date(treasury)),
+            Cell::new(&treasury.security_term),
+            Cell::new(treasury.cusip()),
+            Cell::new_align(
+                if treasury.reopening { "Yes" } else { "No" },
+                Alignment::CENTER,
+            ),
+            Cell::new(&format!("{}", treasury.issue_date.format(datefmt))),
+            Cell::new_align(&format_optional(link.get_size(), 0), Alignment::RIGHT),
+            Cell::new_align(
+                &format_optional(link.get_cumulative_accepted(), 0),
+                Alignment::RIGHT,
+            ),
+            Cell::new_align(
+                &format_optional(treasury.get_bid_to_cover_ratio(), 2),
+                Alignment::RIGHT,
+            ),
+            Cell::new_align(
+                &format_change(link.get_bid_to_cover_change(), 2),
+                Alignment::RIGHT,
+            ),
+            Cell::new_align(
+                &format_percentage(treasury.get_auction_rate(), 3),
+                Alignment::RIGHT,
+            ),
+            Cell::new_align(&format_change(link.get_rate_change(), 1), Alignment::RIGHT),
+        ]);
+
+        if when_issued {
+            row.add_cell(Cell::new_align(
+                &format_percentage(treasury.get_when_issued_yield(), 3),
+                Alignment::RIGHT,
+            ));
+            row.add_cell(Cell::new_align(&format_tail(treasury.get_tail()), Alignment::RIGHT));
+        }
+
+        table.add_row(row);
+    }
+
+    table.printstd()
+}
+
+/// Print the auctions of a reopening chain in a vertical output format.
+pub fn chain_vprint(links: &[ChainLink]) {
+    let mut table = Table::new();
+    let datefmt = Treasury::get_default_date_fmt();
+    table.set_format(*format::consts::FORMAT_CLEAN);
+
+    for link in links {
+        let treasury = link.get_treasury();
+
+        table.add_row(row!["Auction Date:", format_auction_date(treasury)]);
+        table.add_row(row!["Security Term:", &treasury.security_term]);
+        table.add_row(row!["CUSIP", treasury.cusip()]);
+        table.add_row(row![
+            "Reopening:",
+            if treasury.reopening { "Yes" } else { "No" }
+        ]);
+        table.add_row(row!["Issue Date:", treasury.issue_date.format(datefmt)]);
+        table.add_row(row!["Size:", format_optional(link.get_size(), 0)]);
+        table.add_row(row![
+            "Cumulative:",
+            format_optional(link.get_cumulative_accepted(), 0)
+        ]);
+        table.add_row(row![
+            "Bid To Cover:",
+            format!(
+                "{} ({})",
+                format_optional(treasury.get_bid_to_cover_ratio(), 2),
+                format_change(link.get_bid_to_cover_change(), 2)
+            )
+        ]);
+        table.add_row(row![
+            "Rate:",
+            format!(
+                "{} ({} bp)",
+                format_percentage(treasury.get_auction_rate(), 3),
+                format_change(link.get_rate_change(), 1)
+            )
+        ]);
+        add_when_issued_rows(&mut table, treasury);
+        table.add_empty_row();
+    }
+
+    table.printstd()
+}
+
+/// Format the auction date of the treasury.
+fn format_auction_date(treasury: &Treasury) -> String {
+    treasury
+        .get_auction_date()
+        .map_or("n/a".to_owned(), |date| {
+            date.format(Treasury::get_default_date_fmt()).to_string()
+        })
+}
+
+/// Print how much each component moved the quality: its delta to the
+/// reference, the weight of the profile and the weighted contribution.
+pub fn auction_quality_explain_print(quality: &AuctionQuality) {
+    let mut table = Table::new();
+    table.set_format(*format::consts::FORMAT_CLEAN);
+
+    table.add_row(row![
+        "Component",
+        r->"Value",
+        r->"Reference",
+        r->"Delta",
+        r->"Weight",
+        r->"Contribution"
+    ]);
+
+    for contribution in quality.get_contributions() {
+        table.add_row(row![
+            contribution.component().name(),
+            r->format_optional(contribution.value(), 2),
+            r->format_optional(contribution.reference(), 2),
+            r->format_change(contribution.delta(), 2),
+            r->format!("{:+.3}", contribution.weight()),
+            r->format!("{:+.3}", contribution.contribution())
+        ]);
+    }
+
+    table.add_row(row![
+        "Quality",
+        "",
+        "",
+        "",
+        "",
+        r->format!("{:.3}", quality.get())
+    ]);
+    table.add_row(row!["Profile", "", "", "", "", r->quality.get_profile()]);
+
+    table.printstd();
+}
+
+/// Print the qualities of many auctions, one per row.
+pub fn auction_qualities_print(qualities: &[AuctionQuality]) {
+    let mut table = Table::new();
+
+    if qualities.is_empty() {
+        println!("No treasuries matching the criteria available!");
+        return;
+    }
+
+    table.add_row(row![
+        "Auction Date",
+        "Security Term",
+        "CUSIP",
+        "Security Type",
+        "Bid To Cover",
+        "Dealers %",
+        "Indirects %",
+        "Tail",
+        "Quality",
+        "Score",
+        "Profile",
+    ]);
+
+    for quality in qualities {
+        let treasury = quality.get_treasury();
+
+        table.add_row(Row::new(vec![
+            Cell::new(&format_auction_date(&treasury)),
+            Cell::new(&treasury.security_term),
+            Cell::new(treasury.cusip()),
+            Cell::new(&treasury.get_security_type().to_string()),
+            Cell::new_align(
+                &format_optional(treasury.get_bid_to_cover_ratio(), 2),
+                Alignment::RIGHT,
+            ),
+            Cell::new_align(
+                &format_percentage(treasury.get_percentage_debt_purchased_by_dealers(), 2),
+                Alignment::RIGHT,
+            ),
+            Cell::new_align(
+                &format_percentage(treasury.get_percentage_debt_purchased_by_indirects(), 2),
+                Alignment::RIGHT,
+            ),
+            Cell::new_align(&format_tail(quality.get_tail()), Alignment::RIGHT),
+            Cell::new_align(&format!("{:.3}", quality.get()), Alignment::RIGHT),
+            Cell::new_align(
+                &quality
+                    .get_score()
+                    .map_or("n/a".to_owned(), |score| format!("{:.1}", score)),
+                Alignment::RIGHT,
+            ),
+            Cell::new(quality.get_profile()),
+        ]));
+    }
+
+    table.printstd()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::fixture::{api_frn_items, api_multiple_items};
+
+    use super::*;
+
+    #[test]
+    fn it_should_label_the_discount_margin_of_a_mixed_list() {
+        let treasuries: Treasuries = serde_json::from_str(api_multiple_items()).unwrap();
+        let frn = treasuries
+            .iter()
+            .position(|t| t.get_security_type() == SecurityType::Frn)
+            .unwrap();
+        let table = security_table(&treasuries);
+
+        // The header row comes first.
+        let header = table.get_row(0).unwrap();
+        let rate = (0..header.len())
+            .find(|i| header.get_cell(*i).unwrap().get_content() == "High Yield / Margin")
+            .unwrap();
+        assert_eq!(
+            "Interest Rate / Spread",
+            header.get_cell(rate + 1).unwrap().get_content()
+        );
+
+        // The discount margin and the spread of the FRN.
+        let row = table.get_row(frn + 1).unwrap();
+        assert_eq!("0.250%", row.get_cell(rate).unwrap().get_content());
+        assert_eq!("0.170%", row.get_cell(rate + 1).unwrap().get_content());
+
+        // A list of FRNs is labeled by their own columns.
+        let treasuries: Treasuries = serde_json::from_str(&api_frn_items()).unwrap();
+        let header = security_table(&treasuries).get_row(0).unwrap().to_owned();
+        assert!(
+            (0..header.len()).any(|i| header.get_cell(i).unwrap().get_content() == "High Margin")
+        );
+    }
+}
@@ -0,0 +1,294 @@
+//! # Retries and rate limiting of requests.
+//!
+//! TreasuryDirect tends to answer with server errors or to time out when the
+//! results are published. Such requests are retried with an exponential
+//! backoff, while the rate limiter spaces all requests of a client.
+use std::{
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use rand::Rng;
+use reqwest::StatusCode;
+
+use super::error::AuctionResultError;
+
+/// The default number of attempts of a request.
+const MAX_ATTEMPTS: u32 = 3;
+
+/// The delay before the first retry.
+const BASE_DELAY: Duration = Duration::from_millis(500);
+
+/// The upper bound of the delay between two attempts.
+const MAX_DELAY: Duration = Duration::from_secs(8);
+
+/// Describes how often and when a failed request is retried.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    base_delay: Duration,
+    max_delay: Duration,
+    jitter: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: MAX_ATTEMPTS,
+            base_delay: BASE_DELAY,
+            max_delay: MAX_DELAY,
+            jitter: true,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Create a policy with the given number of attempts, including the first one.
+    pub fn new(max_attempts: u32) -> Self {
+        Self {
+            max_attempts: max_attempts.max(1),
+            ..Self::default()
+        }
+    }
+
+    /// Create a policy that never retries.
+    pub fn none() -> Self {
+        Self::new(1)
+    }
+
+    /// Return the policy with the given delay before the first retry.
+    pub fn with_base_delay(mut self, base_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self
+    }
+
+    /// Return the policy with the given upper bound of the delay.
+    pub fn with_max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// Return the policy with or without a random jitter of the delay.
+    pub fn with_jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Return the number of attempts, including the first one.
+    pub fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    /// Return the delay after the given failed attempt, starting at 1. The
+    /// delay doubles with every attempt. The jitter picks a random delay
+    /// between the half and the full delay.
+    pub fn delay(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(16);
+        let delay = self
+            .base_delay
+            .saturating_mul(1 << exponent)
+            .min(self.max_delay);
+
+        if self.jitter {
+            delay.mul_f64(rand::thread_rng().gen_range(0.5..=1.0))
+        } else {
+            delay
+        }
+    }
+
+    /// Return if the request should be tried again after the given attempt failed with the error.
+    pub fn should_retry(&self, attempt: u32, error: &AuctionResultError) -> bool {
+        attempt < self.max_attempts && is_retryable(error)
+    }
+}
+
+/// Return if the error is transient, i. e. a timeout, a connection error or a server error.
+pub fn is_retryable(error: &AuctionResultError) -> bool {
+    match error {
+        AuctionResultError::Timeout(_) => true,
+        AuctionResultError::Request(e) => e.is_timeout() || e.is_connect(),
+        AuctionResultError::Status { status, .. } => {
+            status.is_server_error()
+                || *status == StatusCode::TOO_MANY_REQUESTS
+                || *status == StatusCode::REQUEST_TIMEOUT
+        }
+        _ => false,
+    }
+}
+
+/// Wrap the error of the last attempt, if the request was tried more than once.
+pub(crate) fn give_up(attempts: u32, error: AuctionResultError) -> AuctionResultError {
+    if attempts > 1 {
+        AuctionResultError::Retries {
+            attempts,
+            source: Box::new(error),
+        }
+    } else {
+        error
+    }
+}
+
+/// Spaces the requests by a minimum interval.
+#[derive(Debug, Default)]
+pub struct RateLimiter {
+    interval: Duration,
+    next: Mutex<Option<Instant>>,
+}
+
+impl RateLimiter {
+    /// Create a rate limiter that allows one request per interval.
+    pub fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            next: Mutex::new(None),
+        }
+    }
+
+    /// Create a rate limiter that allows the given number of requests per second.
+    pub fn per_second(requests: u32) -> Self {
+        Self::new(Duration::from_secs(1) / requests.max(1))
+    }
+
+    /// Return the minimum interval between two requests.
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// Reserve the next slot and return how long to wait before sending the request.
+    pub fn reserve(&self) -> Duration {
+        if self.interval.is_zero() {
+            return Duration::ZERO;
+        }
+
+        let now = Instant::now();
+        let mut next = self.next.lock().unwrap_or_else(|e| e.into_inner());
+        let slot = next.map_or(now, |n| n.max(now));
+        *next = Some(slot + self.interval);
+
+        slot - now
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::Matcher;
+
+    use super::*;
+    use crate::{
+        client::TreasuryClient, get::TREASURIES_URL, tests::fixture::api_single_item,
+        treasury::load_async,
+    };
+
+    #[test]
+    fn it_should_back_off_exponentially() {
+        let policy = RetryPolicy::new(5)
+            .with_base_delay(Duration::from_millis(100))
+            .with_max_delay(Duration::from_millis(300))
+            .with_jitter(false);
+
+        assert_eq!(Duration::from_millis(100), policy.delay(1));
+        assert_eq!(Duration::from_millis(200), policy.delay(2));
+        assert_eq!(Duration::from_millis(300), policy.delay(3));
+    }
+
+    #[test]
+    fn it_should_jitter_the_delay() {
+        let policy = RetryPolicy::new(3).with_base_delay(Duration::from_millis(100));
+
+        for _ in 0..10 {
+            let delay = policy.delay(1);
+            assert!(delay >= Duration::from_millis(50) && delay <= Duration::from_millis(100));
+        }
+    }
+
+    #[test]
+    fn it_should_space_the_requests() {
+        let limiter = RateLimiter::new(Duration::from_millis(100));
+
+        assert_eq!(Duration::ZERO, limiter.reserve());
+        assert!(limiter.reserve() > Duration::from_millis(50));
+        assert!(limiter.reserve() > Duration::from_millis(150));
+        assert_eq!(Duration::ZERO, RateLimiter::default().reserve());
+    }
+
+    #[tokio::test]
+    async fn it_should_retry_server_errors() {
+        let mut server = mockito::Server::new_async().await;
+        let client = TreasuryClient::builder()
+            .base_url(server.url())
+            .retry(RetryPolicy::new(3).with_base_delay(Duration::from_millis(1)))
+            .build()
+            .unwrap();
+        let url = format!("{}{}?cusip=91282CJQ5", server.url(), TREASURIES_URL);
+
+        server
+            .mock("GET", TREASURIES_URL)
+            .match_query(Matcher::Any)
+            .with_status(503)
+            .expect(2)
+            .create_async()
+            .await;
+
+        server
+            .mock("GET", TREASURIES_URL)
+            .match_query(Matcher::Any)
+            .with_body(api_single_item())
+            .create_async()
+            .await;
+
+        assert!(load_async(&client, url).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn it_should_report_the_number_of_attempts() {
+        let mut server = mockito::Server::new_async().await;
+        let client = TreasuryClient::builder()
+            .base_url(server.url())
+            .retry(RetryPolicy::new(2).with_base_delay(Duration::from_millis(1)))
+            .build()
+            .unwrap();
+        let url = format!("{}{}?cusip=91282CJQ5", server.url(), TREASURIES_URL);
+
+        let mock = server
+            .mock("GET", TREASURIES_URL)
+            .match_query(Matcher::Any)
+            .with_status(500)
+            .expect(2)
+            .create_async()
+            .await;
+
+        let result = load_async(&client, url).await;
+
+        mock.assert_async().await;
+        assert!(matches!(
+            result,
+            Err(AuctionResultError::Retries { attempts: 2, .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn it_should_not_retry_client_errors() {
+        let mut server = mockito::Server::new_async().await;
+        let client = TreasuryClient::builder()
+            .base_url(server.url())
+            .build()
+            .unwrap();
+        let url = format!("{}{}?cusip=91282CJQ5", server.url(), TREASURIES_URL);
+
+        let mock = server
+            .mock("GET", TREASURIES_URL)
+            .match_query(Matcher::Any)
+            .with_status(404)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let result = load_async(&client, url).await;
+
+        mock.assert_async().await;
+        assert!(matches!(
+            result,
+            Err(AuctionResultError::Status { status: StatusCode::NOT_FOUND, .. })
+        ));
+    }
+}
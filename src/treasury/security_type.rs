@@ -2,13 +2,15 @@
 //!
 //! Represents the different types of treasuries.
 use core::fmt;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::str::FromStr;
 
+use super::Treasury;
+
 #[derive(Debug)]
 pub struct ConvertError;
 
-#[derive(Debug, PartialEq, Deserialize, Default, Clone)]
+#[derive(Debug, PartialEq, Deserialize, Serialize, Default, Clone)]
 pub enum SecurityType {
     Bill,
     Note,
@@ -17,10 +19,26 @@ pub enum SecurityType {
     Frn,
     #[serde(rename = "CMB")]
     Cmb,
+    #[serde(rename = "TIPS")]
+    Tips,
     #[default]
     Null,
 }
 
+impl SecurityType {
+    /// Return if the treasury belongs to this security type. The api returns
+    /// TIPS as notes or bonds, so they are told apart from the nominal ones.
+    pub fn matches(&self, treasury: &Treasury) -> bool {
+        let is_tips = treasury.get_security_type() == SecurityType::Tips;
+
+        match self {
+            SecurityType::Tips => is_tips,
+            SecurityType::Note | SecurityType::Bond => !is_tips,
+            _ => true,
+        }
+    }
+}
+
 impl FromStr for SecurityType {
     type Err = ConvertError;
 
@@ -31,6 +49,7 @@ impl FromStr for SecurityType {
             "BOND" => SecurityType::Bond,
             "FRN" => SecurityType::Frn,
             "CMB" => SecurityType::Cmb,
+            "TIPS" => SecurityType::Tips,
             _ => return Err(ConvertError),
         };
 
@@ -46,6 +65,7 @@ impl fmt::Display for SecurityType {
             SecurityType::Bond => write!(f, "Bond"),
             SecurityType::Frn => write!(f, "FRN"),
             SecurityType::Cmb => write!(f, "CMB"),
+            SecurityType::Tips => write!(f, "TIPS"),
             SecurityType::Null => write!(f, "Null"),
         }
     }
@@ -59,13 +79,14 @@ mod tests {
 
     #[test]
     fn it_should_convert_a_string_to_a_treasury_type() {
-        let variants_as_str: [&str; 5] = ["bilL", "nOte", "Bond", "cmb", "frn"];
-        let expected_variants: [SecurityType; 5] = [
+        let variants_as_str: [&str; 6] = ["bilL", "nOte", "Bond", "cmb", "frn", "tips"];
+        let expected_variants: [SecurityType; 6] = [
             SecurityType::Bill,
             SecurityType::Note,
             SecurityType::Bond,
             SecurityType::Cmb,
             SecurityType::Frn,
+            SecurityType::Tips,
         ];
 
         for (k, v) in variants_as_str.iter().enumerate() {
@@ -75,16 +96,17 @@ mod tests {
 
     #[test]
     fn to_string() {
-        let expected_variants: [SecurityType; 6] = [
+        let expected_variants: [SecurityType; 7] = [
             SecurityType::Bill,
             SecurityType::Note,
             SecurityType::Bond,
             SecurityType::Cmb,
             SecurityType::Frn,
+            SecurityType::Tips,
             SecurityType::Null,
         ];
 
-        let result_variants: [&str; 6] = ["Bill", "Note", "Bond", "CMB", "FRN", "Null"];
+        let result_variants: [&str; 7] = ["Bill", "Note", "Bond", "CMB", "FRN", "TIPS", "Null"];
 
         for (index, variant) in expected_variants.iter().enumerate() {
             assert_eq!(result_variants[index], variant.to_string());
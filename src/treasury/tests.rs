@@ -4,12 +4,15 @@
 extern crate serde;
 
 use chrono::{DateTime, NaiveDateTime};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
 
 use crate::{
     tests::fixture::{
-        api_empty_items, api_empty_response, api_multiple_items, api_single_item, MULTIPLE_ITEMS_COUNT
+        api_30y_bond_item, api_30y_tips_item, api_empty_items, api_empty_response,
+        api_many_items, api_multiple_items, api_single_item, MULTIPLE_ITEMS_COUNT,
     },
-    treasury::Treasury
+    treasury::{SecurityType, Treasury},
 };
 
 #[test]
@@ -55,3 +58,136 @@ fn deserialize_empty_response() {
     let result: Result<Vec<Treasury>, serde_json::Error> = serde_json::from_str(fxt);
     assert!(result.is_err());
 }
+
+#[test]
+fn deserialize_all_fields() {
+    let fxt = api_30y_bond_item();
+    let result: Vec<Treasury> = serde_json::from_str(fxt).unwrap();
+    let treasury = &result[0];
+
+    assert_eq!(Some(dec!(16000000000.0)), treasury.get_offering_amount());
+    assert_eq!(Some(dec!(34059995000.0)), treasury.get_competitive_tendered());
+    assert_eq!(Some(dec!(2683300000.0)), treasury.get_direct_bidder_tendered());
+    assert_eq!(Some(dec!(136220700.0)), treasury.get_soma_accepted());
+    assert_eq!(Some(dec!(0.0)), treasury.get_fima_noncompetitive_accepted());
+    assert_eq!(Some(dec!(2.57)), treasury.get_average_median_yield());
+    assert_eq!(Some(dec!(1.888)), treasury.get_low_yield());
+    assert_eq!(Some(dec!(104.742265)), treasury.get_price_per100());
+    assert_eq!(Some(dec!(19.72)), treasury.get_allocation_percentage());
+    assert_eq!(Some(2019), treasury.get_auction_date_year());
+    assert_eq!(Some("Bonds of May 2049"), treasury.get_series());
+    assert_eq!(Some("912803FJ7"), treasury.get_corpus_cusip());
+    assert_eq!(Some(false), treasury.is_tips());
+    assert_eq!(None, treasury.get_call_date());
+    assert_eq!(
+        "2019-07-03",
+        treasury
+            .get_announcement_date()
+            .unwrap()
+            .format("%Y-%m-%d")
+            .to_string()
+    );
+}
+
+#[test]
+fn reconcile_the_amounts_to_the_dollar() {
+    let result: Vec<Treasury> = serde_json::from_str(api_30y_bond_item()).unwrap();
+    let treasury = &result[0];
+
+    assert_eq!(
+        treasury.get_total_accepted().unwrap(),
+        treasury.get_competitive_accepted().unwrap()
+            + treasury.get_noncompetitive_accepted().unwrap()
+            + treasury.get_soma_accepted().unwrap()
+    );
+    assert_eq!(
+        dec!(33.232306),
+        treasury
+            .get_percentage_debt_purchased_by_dealers()
+            .unwrap()
+            .round_dp(6)
+    );
+
+    let json = serde_json::to_string(&result).unwrap();
+    assert!(json.contains(r#""highYield":2.6440"#));
+    assert!(json.contains(r#""totalAccepted":16136224700"#));
+}
+
+#[test]
+fn deserialize_all_fields_of_many_items() {
+    let result: Vec<Treasury> = serde_json::from_str(api_many_items()).unwrap();
+    assert_eq!(250, result.len());
+}
+
+#[test]
+fn serialize_round_trip() {
+    let treasuries: Vec<Treasury> = serde_json::from_str(api_many_items()).unwrap();
+
+    let json = serde_json::to_string(&treasuries).unwrap();
+    let reloaded: Vec<Treasury> = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(json, serde_json::to_string(&reloaded).unwrap());
+
+    let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!("Bond", value[0]["type"]);
+    assert_eq!("Bond", value[0]["securityType"]);
+    assert_eq!(25000000000.0, value[0]["offeringAmount"].as_f64().unwrap());
+    assert_eq!("2024-02-08T00:00:00", value[0]["auctionDate"]);
+}
+
+#[test]
+fn calculate_the_cover_ratios_of_the_bidders() {
+    let result: Vec<Treasury> = serde_json::from_str(api_many_items()).unwrap();
+    let treasury = &result[0];
+    let round = |value: Option<Decimal>| value.unwrap().round_dp(3);
+
+    // 7391300000 / 3616300000
+    assert_eq!(dec!(2.044), round(treasury.get_directs_cover_ratio()));
+    // 20156389000 / 17638321500
+    assert_eq!(dec!(1.143), round(treasury.get_indirects_cover_ratio()));
+    // 32417200000 / 59964889000
+    assert_eq!(dec!(54.06), round(treasury.get_percentage_tendered_by_dealers()));
+    assert!(Treasury::default().get_dealers_cover_ratio().is_none());
+}
+
+#[test]
+fn detect_tips() {
+    let result: Vec<Treasury> = serde_json::from_str(&api_30y_tips_item()).unwrap();
+    let treasury = &result[0];
+
+    assert_eq!(SecurityType::Tips, treasury.get_security_type());
+    assert_eq!(Some(dec!(2.644)), treasury.get_real_yield());
+    assert_eq!(Some(dec!(255.14387)), treasury.get_ref_cpi_on_issue_date());
+    assert_eq!(Some(dec!(1.01234)), treasury.get_index_ratio_on_issue_date());
+    assert!(SecurityType::Tips.matches(treasury));
+    assert!(!SecurityType::Bond.matches(treasury));
+}
+
+#[test]
+fn rate_frns_by_the_discount_margin() {
+    let result: Vec<Treasury> = serde_json::from_str(api_multiple_items()).unwrap();
+    let frn = result.iter().find(|t| t.cusip() == "91282CJD4").unwrap();
+
+    assert_eq!(SecurityType::Frn, frn.get_security_type());
+    assert_eq!(Some(dec!(0.25)), frn.get_auction_rate());
+    assert_eq!(Some(dec!(0.15)), frn.get_low_discount_margin());
+    assert_eq!(Some(dec!(0.17)), frn.get_spread());
+    assert_eq!(Some(dec!(5.26)), frn.get_frn_index_determination_rate());
+    assert!(frn.get_fields().contains(&"High Margin"));
+}
+
+#[test]
+fn distinguish_missing_values_from_zero() {
+    let mut value: serde_json::Value = serde_json::from_str(api_30y_bond_item()).unwrap();
+    value[0]["highDiscountRate"] = "".into();
+    value[0]["highYield"] = "0.000".into();
+    value[0]["bidToCoverRatio"] = "".into();
+    value[0]["competitiveAccepted"] = "".into();
+    let result: Vec<Treasury> = serde_json::from_value(value).unwrap();
+    let treasury = &result[0];
+
+    assert_eq!(None, treasury.high_discount_rate);
+    assert_eq!(Some(Decimal::ZERO), treasury.high_yield);
+    assert_eq!(None, treasury.get_bid_to_cover_ratio());
+    assert_eq!(None, treasury.get_percentage_debt_purchased_by_dealers());
+}
@@ -0,0 +1,134 @@
+//! # The when-issued yields of auctions.
+//!
+//! The when-issued yield at 1 pm is not part of the api. It is supplied for a
+//! cusip, i. e. from a csv of cusip numbers and yields, to compute the tail of
+//! an auction, see [`Treasury::get_tail`].
+use std::{collections::HashMap, io::BufRead, str::FromStr};
+
+use rust_decimal::Decimal;
+
+use crate::{
+    cusip::Cusip,
+    treasury::{AuctionResult, AuctionResultError, Treasury},
+};
+
+/// The separator of the cusip number and the yield in a line.
+const SEPARATOR: char = ',';
+
+/// The when-issued yields by cusip number.
+#[derive(Debug, Clone, Default)]
+pub struct WhenIssued {
+    yields: HashMap<Cusip, Decimal>,
+}
+
+impl WhenIssued {
+    /// Create an empty set of when-issued yields.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Read the when-issued yields from lines of a cusip number and a yield in
+    /// percent, i. e. "912810SH2,2.624". Empty lines, comments starting with
+    /// "#" and a header starting with "cusip" are skipped.
+    pub fn parse(reader: impl BufRead) -> AuctionResult<Self> {
+        let mut when_issued = Self::new();
+
+        for (number, line) in reader.lines().enumerate() {
+            let error = || AuctionResultError::ParseWhenIssued { line: number + 1 };
+            let line = line.map_err(|_| error())?;
+            let line = line.trim();
+
+            if line.is_empty()
+                || line.starts_with('#')
+                || (number == 0 && line.to_ascii_lowercase().starts_with("cusip"))
+            {
+                continue;
+            }
+
+            let Some((cusip, when_issued_yield)) = line.split_once(SEPARATOR) else {
+                return Err(error());
+            };
+
+            when_issued.insert(
+                Cusip::parse(cusip)?,
+                Decimal::from_str(when_issued_yield.trim()).map_err(|_| error())?,
+            );
+        }
+
+        Ok(when_issued)
+    }
+
+    /// Set the when-issued yield of the cusip number.
+    pub fn insert(&mut self, cusip: Cusip, when_issued_yield: Decimal) {
+        self.yields.insert(cusip, when_issued_yield);
+    }
+
+    /// Return the when-issued yield of the cusip number.
+    pub fn get(&self, cusip: &Cusip) -> Option<Decimal> {
+        self.yields.get(cusip).copied()
+    }
+
+    /// Return if there are no when-issued yields.
+    pub fn is_empty(&self) -> bool {
+        self.yields.is_empty()
+    }
+
+    /// Set the when-issued yields of the treasuries with a known cusip number.
+    pub fn apply(&self, treasuries: &mut [Treasury]) {
+        for treasury in treasuries {
+            if let Some(when_issued_yield) = self.get(treasury.get_cusip()) {
+                treasury.set_when_issued_yield(when_issued_yield);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use crate::{
+        tests::fixture::{api_30y_bond_item, api_frn_items},
+        treasury::Treasuries,
+    };
+
+    use super::*;
+
+    #[test]
+    fn it_should_compute_the_tail_of_an_auction() {
+        let csv = "cusip,wi\n# 30-Year\n912810SH2, 2.624\n\n91282CJD4,4.5\n";
+        let when_issued = WhenIssued::parse(csv.as_bytes()).unwrap();
+        let mut treasuries: Treasuries = serde_json::from_str(api_30y_bond_item()).unwrap();
+
+        when_issued.apply(&mut treasuries);
+
+        // The high yield is 2.644%.
+        assert_eq!(Some(dec!(2.624)), treasuries[0].get_when_issued_yield());
+        assert_eq!(Some(dec!(2.0)), treasuries[0].get_tail());
+
+        treasuries[0].set_when_issued_yield(dec!(2.65));
+        assert_eq!(Some(dec!(-0.6)), treasuries[0].get_tail());
+    }
+
+    #[test]
+    fn it_should_name_the_line_that_failed_to_parse() {
+        let error = WhenIssued::parse("912810SH2,2.624\n912810SH2;2.6".as_bytes()).unwrap_err();
+
+        assert!(matches!(error, AuctionResultError::ParseWhenIssued { line: 2 }));
+        assert!(matches!(
+            WhenIssued::parse("912810SH3,2.6".as_bytes()),
+            Err(AuctionResultError::CheckDigit { .. })
+        ));
+    }
+
+    #[test]
+    fn it_should_not_compute_a_tail_of_a_frn() {
+        let mut treasuries: Treasuries = serde_json::from_str(&api_frn_items()).unwrap();
+
+        // The discount margin of 0.25% is no yield to compare to.
+        treasuries[0].set_when_issued_yield(dec!(4.5));
+
+        assert_eq!(Some(dec!(4.5)), treasuries[0].get_when_issued_yield());
+        assert_eq!(None, treasuries[0].get_tail());
+    }
+}